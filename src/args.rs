@@ -0,0 +1,51 @@
+// Arguments of parameterized scalar functions.
+//
+// DuckDB flattens constant and dictionary vectors before it calls a C API
+// scalar function, so every argument arrives as a flat vector with one entry
+// per row. What is left to get right is the validity mask: a NULL row holds
+// arbitrary bytes. The readers below return NULL rows as None, and `rows`
+// combines several arguments so that a row is None as soon as any of them is
+// NULL, which is how every parameterized function propagates NULL.
+
+use duckdb::core::{DataChunkHandle, FlatVector};
+use duckdb::types::DuckString;
+use libduckdb_sys as ffi;
+
+// Column `col` as values of the native type T (i64 for BIGINT and DECIMAL(18,x),
+// f64 for DOUBLE, i32 for DATE, ...)
+pub unsafe fn column<T: Copy>(input: &DataChunkHandle, col: usize) -> Vec<Option<T>> {
+    let vector = input.flat_vector(col);
+    let data = vector.as_slice_with_len::<T>(input.len());
+    data.iter()
+        .enumerate()
+        .map(|(i, &value)| (!vector.row_is_null(i as u64)).then_some(value))
+        .collect()
+}
+
+// VARCHAR column `col`
+pub unsafe fn strings(input: &DataChunkHandle, col: usize) -> Vec<Option<String>> {
+    column::<ffi::duckdb_string_t>(input, col)
+        .into_iter()
+        .map(|value| value.map(|mut value| DuckString::new(&mut value).as_str().to_string()))
+        .collect()
+}
+
+// LIST column `col` as the offset and length of each row's elements in
+// `elements(input, col)`
+pub unsafe fn lists(input: &DataChunkHandle, col: usize) -> Vec<Option<(usize, usize)>> {
+    column::<ffi::duckdb_list_entry>(input, col)
+        .into_iter()
+        .map(|entry| entry.map(|entry| (entry.offset as usize, entry.length as usize)))
+        .collect()
+}
+
+// The elements of all rows of LIST column `col`
+pub fn elements(input: &DataChunkHandle, col: usize) -> FlatVector {
+    let list = input.list_vector(col);
+    list.child(list.len())
+}
+
+// Pair up two argument columns row by row; None where either is NULL
+pub fn rows<A, B>(a: Vec<Option<A>>, b: Vec<Option<B>>) -> Vec<Option<(A, B)>> {
+    a.into_iter().zip(b).map(|(a, b)| a.zip(b)).collect()
+}
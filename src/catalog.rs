@@ -0,0 +1,95 @@
+// Catalog queries for table functions that read the schema of other tables.
+//
+// The C API gives the bind step of a table function no way to query through
+// the connection that called it, and the database handle it passes to the
+// entrypoint is only valid while the extension loads. So the entrypoint opens
+// a few connections up front and registers the table functions that read the
+// catalog with them (see lib.rs). A query takes a connection for as long as it
+// runs and puts it back afterwards, so calls that nest, such as a
+// fakeit_children query over fakeit_children, each get their own; once all of
+// them are taken the innermost call fails instead of waiting forever.
+//
+// These connections are not the caller's: they see committed state only, so
+// temporary tables and changes in the caller's open transaction are out of
+// reach, and they have their own current database and schema. The functions
+// keep them for as long as DuckDB keeps the functions, which holds the
+// database instance open until the process exits.
+
+use duckdb::{params_from_iter, Connection};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+// How deep catalog queries can nest, or how many can run at once
+const CONNECTIONS: usize = 8;
+
+#[derive(Clone)]
+pub struct Catalog {
+    idle: Arc<Mutex<Vec<Connection>>>,
+}
+
+// A connection taken from the catalog, returned when dropped
+pub struct Taken {
+    connection: Option<Connection>,
+    idle: Arc<Mutex<Vec<Connection>>>,
+}
+
+impl Deref for Taken {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.connection.as_ref().unwrap()
+    }
+}
+
+impl Drop for Taken {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.idle.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(connection);
+        }
+    }
+}
+
+impl Catalog {
+    // Must be called while the extension loads
+    pub fn new(con: &Connection) -> Result<Catalog, duckdb::Error> {
+        let connections = (0..CONNECTIONS).map(|_| con.try_clone()).collect::<Result<_, _>>()?;
+        Ok(Catalog {
+            idle: Arc::new(Mutex::new(connections)),
+        })
+    }
+
+    pub fn take(&self) -> Result<Taken, String> {
+        let connection = self.idle.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).pop();
+        match connection {
+            Some(connection) => Ok(Taken {
+                connection: Some(connection),
+                idle: Arc::clone(&self.idle),
+            }),
+            None => Err(format!(
+                "all {} catalog connections are busy; calls that read the catalog nest at most {} deep",
+                CONNECTIONS, CONNECTIONS
+            )),
+        }
+    }
+
+    // Rows of a query whose columns are all VARCHAR, NULL as None
+    pub fn query(&self, sql: &str, params: &[&str]) -> Result<Vec<Vec<Option<String>>>, String> {
+        let connection = self.take()?;
+        let run = || -> Result<Vec<Vec<Option<String>>>, duckdb::Error> {
+            let mut statement = connection.prepare(sql)?;
+            let mut rows = statement.query(params_from_iter(params))?;
+            let count = rows.as_ref().map_or(0, |statement| statement.column_count());
+            let mut result = Vec::new();
+            while let Some(row) = rows.next()? {
+                result.push((0..count).map(|i| row.get::<_, Option<String>>(i)).collect::<Result<_, _>>()?);
+            }
+            Ok(result)
+        };
+        run().map_err(|e| e.to_string())
+    }
+}
+
+// `name` as a quoted SQL identifier
+pub fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
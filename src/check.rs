@@ -0,0 +1,323 @@
+// CHECK constraints that fakeit_fill knows how to satisfy.
+//
+// DuckDB stores a CHECK expression in a normalized form such as
+// ((age >= 18) AND (age < 100)). The forms understood here are conjunctions
+// of comparisons between a column and a constant, BETWEEN and IN. Constants
+// are kept as SQL text, so that the caller can evaluate them in the type of
+// their column.
+
+pub enum Condition {
+    // At least the constant, or greater than it when strict
+    Lower(String, bool),
+    // At most the constant, or less than it when strict
+    Upper(String, bool),
+    OneOf(Vec<String>),
+}
+
+#[derive(Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Comma,
+    Op(String),
+    Word(String),
+    // A double-quoted identifier
+    Quoted(String),
+    // A number or string constant, as written
+    Constant(String),
+}
+
+enum Operand {
+    Column(String),
+    Constant(String),
+}
+
+// The conditions on each column, or None when the expression is not a
+// conjunction of understood forms
+pub fn parse(expression: &str) -> Option<Vec<(String, Condition)>> {
+    let mut parser = Parser {
+        tokens: tokenize(expression)?,
+        pos: 0,
+    };
+    let conditions = parser.conjunction()?;
+    (parser.pos == parser.tokens.len()).then_some(conditions)
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            ',' => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            '\'' | '"' => {
+                // Quotes inside are doubled
+                let mut text = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some(&q) if q == c && chars.get(i + 1) == Some(&c) => {
+                            text.push(c);
+                            i += 2;
+                        }
+                        Some(&q) if q == c => break,
+                        Some(&other) => {
+                            text.push(other);
+                            i += 1;
+                        }
+                        None => return None,
+                    }
+                }
+                i += 1;
+                tokens.push(if c == '"' {
+                    Token::Quoted(text)
+                } else {
+                    Token::Constant(format!("'{}'", text.replace('\'', "''")))
+                });
+            }
+            '0'..='9' | '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    i += 1;
+                }
+                tokens.push(Token::Constant(chars[start..i].iter().collect()));
+            }
+            _ if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            }
+            _ => {
+                let two: String = chars[i..chars.len().min(i + 2)].iter().collect();
+                let op = match two.as_str() {
+                    ">=" | "<=" | "<>" | "!=" | "::" => two,
+                    _ => c.to_string(),
+                };
+                i += op.chars().count();
+                tokens.push(Token::Op(op));
+            }
+        }
+    }
+    Some(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, token: Token) -> Option<()> {
+        (self.next()? == token).then_some(())
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn conjunction(&mut self) -> Option<Vec<(String, Condition)>> {
+        let mut conditions = self.term()?;
+        while self.keyword("AND") {
+            conditions.extend(self.term()?);
+        }
+        Some(conditions)
+    }
+
+    fn term(&mut self) -> Option<Vec<(String, Condition)>> {
+        if self.peek() == Some(&Token::Open) {
+            let start = self.pos;
+            self.pos += 1;
+            if let Some(conditions) = self.conjunction() {
+                if self.next() == Some(Token::Close) {
+                    return Some(conditions);
+                }
+            }
+            // Not a group after all, e.g. a parenthesized operand
+            self.pos = start;
+        }
+        self.predicate()
+    }
+
+    fn predicate(&mut self) -> Option<Vec<(String, Condition)>> {
+        let left = self.operand()?;
+        if self.keyword("BETWEEN") {
+            let (Operand::Column(column), Operand::Constant(low)) = (left, self.operand()?) else {
+                return None;
+            };
+            if !self.keyword("AND") {
+                return None;
+            }
+            let Operand::Constant(high) = self.operand()? else {
+                return None;
+            };
+            return Some(vec![
+                (column.clone(), Condition::Lower(low, false)),
+                (column, Condition::Upper(high, false)),
+            ]);
+        }
+        if self.keyword("IN") {
+            let Operand::Column(column) = left else {
+                return None;
+            };
+            self.expect(Token::Open)?;
+            let mut values = Vec::new();
+            loop {
+                values.push(self.constant()?);
+                match self.next()? {
+                    Token::Comma => continue,
+                    Token::Close => break,
+                    _ => return None,
+                }
+            }
+            return Some(vec![(column, Condition::OneOf(values))]);
+        }
+
+        let Some(Token::Op(op)) = self.next() else {
+            return None;
+        };
+        let right = self.operand()?;
+        // Put the column on the left
+        let (column, op, constant) = match (left, right) {
+            (Operand::Column(column), Operand::Constant(constant)) => (column, op, constant),
+            (Operand::Constant(constant), Operand::Column(column)) => {
+                let flipped = match op.as_str() {
+                    ">=" => "<=",
+                    ">" => "<",
+                    "<=" => ">=",
+                    "<" => ">",
+                    other => other,
+                };
+                (column, flipped.to_string(), constant)
+            }
+            _ => return None,
+        };
+        let condition = match op.as_str() {
+            ">=" => Condition::Lower(constant, false),
+            ">" => Condition::Lower(constant, true),
+            "<=" => Condition::Upper(constant, false),
+            "<" => Condition::Upper(constant, true),
+            "=" => Condition::OneOf(vec![constant]),
+            _ => return None,
+        };
+        Some(vec![(column, condition)])
+    }
+
+    fn operand(&mut self) -> Option<Operand> {
+        match self.peek()? {
+            Token::Quoted(name) => {
+                let name = name.clone();
+                self.pos += 1;
+                Some(Operand::Column(name))
+            }
+            Token::Word(word)
+                if !["CAST", "TRUE", "FALSE", "NULL"].iter().any(|k| word.eq_ignore_ascii_case(k))
+                    && !matches!(self.tokens.get(self.pos + 1), Some(Token::Constant(_))) =>
+            {
+                let name = word.clone();
+                self.pos += 1;
+                Some(Operand::Column(name))
+            }
+            _ => self.constant().map(Operand::Constant),
+        }
+    }
+
+    // A constant as SQL text: a number, a string, TRUE or FALSE, a typed
+    // string such as DATE '2024-01-01', or a cast of a constant
+    fn constant(&mut self) -> Option<String> {
+        let mut constant = match self.next()? {
+            Token::Constant(text) => text,
+            Token::Op(op) if op == "-" || op == "+" => match self.next()? {
+                Token::Constant(number) if !number.starts_with('\'') => format!("{}{}", op, number),
+                _ => return None,
+            },
+            Token::Open => {
+                let inner = self.constant()?;
+                self.expect(Token::Close)?;
+                inner
+            }
+            Token::Word(word) if word.eq_ignore_ascii_case("CAST") => {
+                self.expect(Token::Open)?;
+                let inner = self.constant()?;
+                if !self.keyword("AS") {
+                    return None;
+                }
+                let type_name = self.type_name()?;
+                self.expect(Token::Close)?;
+                format!("CAST({} AS {})", inner, type_name)
+            }
+            Token::Word(word) if word.eq_ignore_ascii_case("TRUE") || word.eq_ignore_ascii_case("FALSE") => word,
+            Token::Word(word) => match self.next()? {
+                Token::Constant(text) if text.starts_with('\'') => format!("CAST({} AS {})", text, word),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        while self.peek() == Some(&Token::Op("::".to_string())) {
+            self.pos += 1;
+            constant = format!("CAST({} AS {})", constant, self.type_name()?);
+        }
+        Some(constant)
+    }
+
+    // A type such as INTEGER, DECIMAL(10,2) or TIMESTAMP WITH TIME ZONE
+    fn type_name(&mut self) -> Option<String> {
+        let Some(Token::Word(first)) = self.next() else {
+            return None;
+        };
+        let mut words = vec![first];
+        // Only the words of multi-word types, so that a following AND is left alone
+        while let Some(Token::Word(word)) = self.peek() {
+            if !["WITH", "WITHOUT", "TIME", "ZONE", "PRECISION"].iter().any(|w| word.eq_ignore_ascii_case(w)) {
+                break;
+            }
+            words.push(word.clone());
+            self.pos += 1;
+        }
+        let mut type_name = words.join(" ");
+        if self.peek() == Some(&Token::Open) {
+            self.pos += 1;
+            let mut modifiers = Vec::new();
+            loop {
+                match self.next()? {
+                    Token::Constant(number) => modifiers.push(number),
+                    Token::Comma => {}
+                    Token::Close => break,
+                    _ => return None,
+                }
+            }
+            type_name = format!("{}({})", type_name, modifiers.join(", "));
+        }
+        Some(type_name)
+    }
+}
@@ -0,0 +1,376 @@
+// The fakeit_children table function: child rows that reference existing
+// parents, for generating orders of customers, lines of orders and so on.
+//
+//   FROM fakeit_children(parent_keys := 'SELECT id FROM customers', per_parent := 'poisson(3)')
+//
+// Table functions cannot take subqueries, so `parent_keys` is the text of a
+// query whose first column holds the keys. It runs on a catalog connection
+// (see catalog.rs), so it sees committed tables only: bind only reads the type of the keys and DuckDB's estimate
+// of their number, and the scan runs the query in a thread of its own and
+// takes the keys from it a batch at a time. Every parent gets a number of
+// children drawn from the `per_parent` distribution, which may be zero, and
+// one output row per child. Keys and counts are taken as the rows are
+// produced, so only DuckDB's own result of the query holds every key, and a
+// heavy-tailed distribution does not have to fit in memory.
+
+use duckdb::core::{DataChunkHandle, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab};
+use duckdb::Connection;
+use libduckdb_sys as ffi;
+use std::error::Error;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Mutex;
+use std::thread;
+
+use super::catalog::{quote, Catalog, Taken};
+use super::distribution;
+use super::functions::{self, Parameters, Type};
+use super::kind::{self, Kind, Value};
+use super::number;
+use super::rng;
+
+const KEY_COLUMN: &str = "parent_key";
+
+// Keys are handed over this many at a time, and the thread that reads them
+// runs at most BATCHES_AHEAD batches ahead of the scan
+const BATCH_SIZE: usize = 2048;
+const BATCHES_AHEAD: usize = 2;
+
+// A count distribution such as poisson(3): the name of a distribution
+// function and its arguments
+struct PerParent {
+    name: String,
+    args: Vec<f64>,
+}
+
+impl PerParent {
+    // "3", "poisson(3)", "zipf(100, 1.1)", ...
+    fn parse(spec: &str) -> Result<PerParent, String> {
+        let spec = spec.trim();
+        if let Ok(count) = spec.parse::<u64>() {
+            return Ok(PerParent {
+                name: "fixed".to_string(),
+                args: vec![count as f64],
+            });
+        }
+        let invalid = || {
+            format!(
+                "per_parent must be a count or a distribution such as 'poisson(3)', got '{}'",
+                spec
+            )
+        };
+        let (name, args) = spec.strip_suffix(')').and_then(|s| s.split_once('(')).ok_or_else(invalid)?;
+        let args = args
+            .split(',')
+            .map(|arg| arg.trim().parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+        let per_parent = PerParent {
+            name: name.trim().to_lowercase(),
+            args,
+        };
+        // Check the parameters once, without disturbing the shared stream
+        rng::with_key(0, || per_parent.draw()).map_err(|e| format!("per_parent '{}': {}", spec, e))?;
+        Ok(per_parent)
+    }
+
+    // The number of children of one parent, rounded down and at least 0
+    fn draw(&self) -> Result<u64, String> {
+        let count = match (self.name.as_str(), self.args.as_slice()) {
+            ("fixed", &[count]) => count,
+            ("uniform", &[min, max]) => number::int(min as i128, max as i128)? as f64,
+            ("poisson", &[lambda]) => distribution::poisson(lambda)? as f64,
+            ("binomial", &[n, p]) => distribution::binomial(n as i64, p)? as f64,
+            ("zipf", &[n, s]) => distribution::zipf(n as i64, s)? as f64,
+            ("bernoulli", &[p]) => distribution::bernoulli(p)? as f64,
+            ("normal", &[mean, sd]) => distribution::normal(mean, sd)?,
+            ("lognormal", &[mu, sigma]) => distribution::lognormal(mu, sigma)?,
+            ("exponential", &[rate]) => distribution::exponential(rate)?,
+            ("pareto", &[scale, shape]) => distribution::pareto(scale, shape)?,
+            ("gamma", &[shape, scale]) => distribution::gamma(shape, scale)?,
+            _ => {
+                return Err(format!(
+                    "unknown distribution '{}' with {} arguments; expected uniform, poisson, binomial, zipf, \
+                     bernoulli, normal, lognormal, exponential, pareto or gamma",
+                    self.name,
+                    self.args.len()
+                ))
+            }
+        };
+        // Saturates for huge draws; NaN cannot come out of the samplers
+        Ok(count.floor().max(0.0) as u64)
+    }
+
+    // The expected number of children per parent, before rounding down
+    fn mean(&self) -> f64 {
+        let mean = match (self.name.as_str(), self.args.as_slice()) {
+            ("fixed", &[count]) => count,
+            ("uniform", &[min, max]) => (min + max) / 2.0,
+            ("poisson", &[lambda]) => lambda,
+            ("binomial", &[n, p]) => n * p,
+            ("zipf", &[n, s]) => power_sum(n, s - 1.0) / power_sum(n, s),
+            ("bernoulli", &[p]) => p,
+            ("normal", &[mean, _]) => mean,
+            ("lognormal", &[mu, sigma]) => (mu + sigma * sigma / 2.0).exp(),
+            ("exponential", &[rate]) => 1.0 / rate,
+            ("pareto", &[scale, shape]) if shape > 1.0 => shape * scale / (shape - 1.0),
+            ("gamma", &[shape, scale]) => shape * scale,
+            _ => f64::INFINITY,
+        };
+        mean.max(0.0)
+    }
+}
+
+// The sum of k^-s for k from 1 to n: exact for the first terms, and the
+// integral of the rest
+fn power_sum(n: f64, s: f64) -> f64 {
+    const EXACT: f64 = 10_000.0;
+    let exact = (1..=n.min(EXACT) as u64).map(|k| (k as f64).powf(-s)).sum::<f64>();
+    if n <= EXACT {
+        return exact;
+    }
+    let integral = |x: f64| if (s - 1.0).abs() < 1e-9 { x.ln() } else { x.powf(1.0 - s) / (1.0 - s) };
+    exact + integral(n + 0.5) - integral(EXACT + 0.5)
+}
+
+pub struct ChildrenBindData {
+    catalog: Catalog,
+    // Selects the keys as canonical text, see kind.rs
+    sql: String,
+    kind: Kind,
+    per_parent: PerParent,
+    max_per_parent: u64,
+}
+
+// A batch of keys, or None after the last
+type Batch = Result<Option<Vec<Value>>, String>;
+
+// Keys as they arrive from the streaming thread
+struct Keys {
+    batches: Receiver<Batch>,
+    batch: std::vec::IntoIter<Value>,
+    done: bool,
+}
+
+impl Keys {
+    // Run `sql` on `connection` in a thread of its own
+    fn stream(connection: Taken, sql: String, kind: Kind) -> Keys {
+        let (sender, batches) = mpsc::sync_channel(BATCHES_AHEAD);
+        thread::spawn(move || {
+            if let Err(e) = send_keys(&connection, &sql, kind, &sender) {
+                // Nobody is listening any more if this fails too
+                let _ = sender.send(Err(e));
+            }
+        });
+        Keys {
+            batches,
+            batch: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+
+    // The next key, waiting for the thread if needed; None after the last
+    fn next(&mut self) -> Result<Option<Value>, String> {
+        loop {
+            if let Some(key) = self.batch.next() {
+                return Ok(Some(key));
+            }
+            if self.done {
+                return Ok(None);
+            }
+            match self.batches.recv() {
+                Ok(Ok(Some(batch))) => self.batch = batch.into_iter(),
+                Ok(Ok(None)) => self.done = true,
+                Ok(Err(e)) => return Err(e),
+                Err(_) => return Err("the query stopped unexpectedly".to_string()),
+            }
+        }
+    }
+}
+
+// Where the next chunk starts: the current parent and its children still to
+// emit
+struct Cursor {
+    keys: Keys,
+    current: Option<Value>,
+    count: u64,
+    child: u64,
+}
+
+pub struct ChildrenInitData {
+    cursor: Mutex<Cursor>,
+}
+
+pub struct ChildrenTable;
+
+impl Parameters for ChildrenTable {
+    fn named_parameters() -> Vec<(&'static str, Type)> {
+        vec![
+            ("parent_keys", Type::Varchar),
+            ("per_parent", Type::Varchar),
+            ("max_per_parent", Type::Bigint),
+        ]
+    }
+
+    fn register(con: &Connection, name: &str, catalog: &Catalog) -> duckdb::Result<()> {
+        con.register_table_function_with_extra_info::<Self, _>(name, catalog)
+    }
+}
+
+impl VTab for ChildrenTable {
+    type InitData = ChildrenInitData;
+    type BindData = ChildrenBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn Error>> {
+        let query = match bind.get_named_parameter("parent_keys") {
+            Some(query) => query.to_string(),
+            None => return Err("fakeit_children: the parent_keys parameter is required".into()),
+        };
+        let per_parent = match bind.get_named_parameter("per_parent") {
+            Some(spec) => PerParent::parse(&spec.to_string()).map_err(|e| format!("fakeit_children: {}", e))?,
+            None => return Err("fakeit_children: the per_parent parameter is required".into()),
+        };
+        let max_per_parent = match bind.get_named_parameter("max_per_parent") {
+            Some(max) if max.to_int64() < 0 => {
+                return Err("fakeit_children: max_per_parent must not be negative".into());
+            }
+            Some(max) => max.to_int64() as u64,
+            None => u64::MAX,
+        };
+
+        let catalog = unsafe { &*bind.get_extra_info::<Catalog>() };
+        let query = query.trim().trim_end_matches(';');
+        let kind = key_kind(catalog, query).map_err(|e| format!("fakeit_children: {}", e))?;
+        bind.add_result_column(KEY_COLUMN, kind.logical_type());
+        bind.add_result_column("child_index", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        if let Some(parents) = estimated_rows(catalog, query) {
+            let children = parents * per_parent.mean().min(max_per_parent as f64);
+            bind.set_cardinality(children.min(u64::MAX as f64) as u64, false);
+        }
+
+        let column = quote(KEY_COLUMN);
+        let sql = format!(
+            "SELECT {} FROM ({}) AS parents({}) WHERE {} IS NOT NULL",
+            kind.canonical(&column),
+            query,
+            column,
+            column
+        );
+        Ok(ChildrenBindData {
+            catalog: catalog.clone(),
+            sql,
+            kind,
+            per_parent,
+            max_per_parent,
+        })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn Error>> {
+        let bind_data = unsafe { &*init.get_bind_data::<ChildrenBindData>() };
+        let connection = bind_data.catalog.take().map_err(|e| format!("fakeit_children: {}", e))?;
+        Ok(ChildrenInitData {
+            cursor: Mutex::new(Cursor {
+                keys: Keys::stream(connection, bind_data.sql.clone(), bind_data.kind),
+                current: None,
+                count: 0,
+                child: 0,
+            }),
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn Error>> {
+        let bind_data = func.get_bind_data();
+        let init_data = func.get_init_data();
+        let chunk_size = unsafe { ffi::duckdb_vector_size() } as usize;
+
+        let mut cursor = init_data.cursor.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Released while waiting for keys, which may come from a query that
+        // generates values itself
+        let mut stream = Some(rng::lock());
+        let mut keys = output.flat_vector(0);
+        let mut indexes = output.flat_vector(1);
+        let mut len = 0;
+        while len < chunk_size {
+            if cursor.child < cursor.count {
+                let key = cursor.current.as_ref().unwrap();
+                kind::write(&mut keys, bind_data.kind, len, key);
+                cursor.child += 1;
+                indexes.as_mut_slice::<i64>()[len] = cursor.child as i64;
+                len += 1;
+                continue;
+            }
+            drop(stream.take());
+            let key = cursor.keys.next().map_err(|e| format!("fakeit_children: parent_keys: {}", e))?;
+            stream = Some(rng::lock());
+            let Some(key) = key else {
+                break;
+            };
+            let count = bind_data.per_parent.draw().map_err(|e| format!("fakeit_children: {}", e))?;
+            cursor.current = Some(key);
+            cursor.count = count.min(bind_data.max_per_parent);
+            cursor.child = 0;
+        }
+        output.set_len(len);
+        Ok(())
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        functions::named_parameters::<Self>()
+    }
+}
+
+// The type of the first column of `query`
+fn key_kind(catalog: &Catalog, query: &str) -> Result<Kind, String> {
+    let described = catalog
+        .query(&format!("SELECT column_type FROM (DESCRIBE {}) LIMIT 1", query), &[])
+        .map_err(|e| {
+            if e.contains("Catalog Error") {
+                format!(
+                    "parent_keys: {}; the query sees committed tables only, so temporary tables and tables \
+                     created in an open transaction cannot be read",
+                    e
+                )
+            } else {
+                format!("parent_keys: {}", e)
+            }
+        })?;
+    let data_type = described
+        .first()
+        .and_then(|row| row[0].clone())
+        .ok_or("parent_keys must be a query that returns a column of keys")?;
+    Kind::parse(&data_type).ok_or_else(|| format!("parent keys of type {} are not supported", data_type))
+}
+
+// DuckDB's estimate of the number of rows of `query`, without running it
+fn estimated_rows(catalog: &Catalog, query: &str) -> Option<f64> {
+    let rows = catalog.query(&format!("EXPLAIN (FORMAT json) {}", query), &[]).ok()?;
+    let plan: serde_json::Value = serde_json::from_str(rows.first()?.get(1)?.as_deref()?).ok()?;
+    plan[0]["extra_info"]["Estimated Cardinality"].as_str()?.parse().ok()
+}
+
+// Send the keys selected by `sql` in batches and then None, unless the scan
+// stops listening first
+fn send_keys(connection: &Taken, sql: &str, kind: Kind, sender: &SyncSender<Batch>) -> Result<(), String> {
+    let mut statement = connection.prepare(sql).map_err(|e| e.to_string())?;
+    let mut rows = statement.query([]).map_err(|e| e.to_string())?;
+    let mut batch = Vec::with_capacity(BATCH_SIZE);
+    loop {
+        let row = rows.next().map_err(|e| e.to_string())?;
+        if let Some(row) = row {
+            let text: String = row.get(0).map_err(|e| e.to_string())?;
+            batch.push(kind.value(&text).ok_or("cannot read the parent keys")?);
+            if batch.len() < BATCH_SIZE {
+                continue;
+            }
+        }
+        let last = row.is_none();
+        if !batch.is_empty() && sender.send(Ok(Some(std::mem::take(&mut batch)))).is_err() {
+            return Ok(());
+        }
+        if last {
+            // Fails only when the scan stopped listening
+            let _ = sender.send(Ok(None));
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Random choice from list arguments, for fakeit_choice and fakeit_weighted_choice.
+//
+// Weighted choice samples from an alias table (Vose's method), which takes
+// O(n) to build and O(1) per draw. DuckDB flattens a constant list by giving
+// every row the same offset and length into one element vector, so the table
+// of the previous row is reused while the weights entry stays the same.
+
+use duckdb::core::{FlatVector, Inserter, LogicalTypeId};
+use duckdb::types::DuckString;
+use libduckdb_sys as ffi;
+
+use super::rng;
+
+pub struct AliasTable {
+    // Probability of keeping column i rather than taking its alias
+    probability: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    pub fn new(weights: &[f64]) -> Result<Self, String> {
+        if let Some(weight) = weights.iter().find(|w| !(w.is_finite() && **w >= 0.0)) {
+            return Err(format!("weights must be non-negative numbers, got {}", weight));
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return Err("weights must not all be zero".to_string());
+        }
+
+        let n = weights.len();
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut probability = vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| scaled[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            probability[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] -= 1.0 - scaled[s];
+            if scaled[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // Whatever is left over is 1 up to rounding
+        Ok(AliasTable { probability, alias })
+    }
+
+    pub fn sample(&self) -> usize {
+        let i = rng::below(self.probability.len() as u64) as usize;
+        if rng::unit() < self.probability[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+// Write element `picks[i]` of `elements` to row i of `output`, NULL for None
+// or a NULL element
+pub unsafe fn write_picks(elements: &FlatVector, picks: &[Option<usize>], output: &mut FlatVector) {
+    match elements.logical_type().id() {
+        LogicalTypeId::Varchar => {
+            let strings = elements.as_slice::<ffi::duckdb_string_t>();
+            for (i, pick) in picks.iter().enumerate() {
+                match pick.filter(|&p| !elements.row_is_null(p as u64)) {
+                    Some(p) => {
+                        let mut value = strings[p];
+                        output.insert(i, DuckString::new(&mut value).as_bytes());
+                    }
+                    None => output.set_null(i),
+                }
+            }
+        }
+        LogicalTypeId::Boolean => copy_picks::<bool>(elements, picks, output),
+        LogicalTypeId::Integer | LogicalTypeId::Date => copy_picks::<i32>(elements, picks, output),
+        LogicalTypeId::Uuid => copy_picks::<ffi::duckdb_hugeint>(elements, picks, output),
+        // BIGINT, DOUBLE, DECIMAL(18,2) and the timestamps are all 8 bytes wide
+        _ => copy_picks::<i64>(elements, picks, output),
+    }
+}
+
+unsafe fn copy_picks<T: Copy>(elements: &FlatVector, picks: &[Option<usize>], output: &mut FlatVector) {
+    let values = elements.as_slice::<T>();
+    for (i, pick) in picks.iter().enumerate() {
+        match pick.filter(|&p| !elements.row_is_null(p as u64)) {
+            Some(p) => output.as_mut_slice::<T>()[i] = values[p],
+            None => output.set_null(i),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Colors as structured values: RGB channels, their HSL and CMYK equivalents,
+// and palettes of distinct colors that stay readable on a white background.
+
+use super::rng;
+
+// Minimum WCAG contrast ratio of every palette color against white; 3:1 is the
+// level WCAG 2.1 asks of graphical objects and user interface components
+const PALETTE_CONTRAST: f64 = 3.0;
+
+// Palette colors start out at least this far apart in RGB space; the distance
+// is relaxed step by step when a large palette runs out of room
+const PALETTE_DISTANCE: f64 = 120.0;
+
+pub const PALETTE_MAX: i64 = 256;
+
+pub fn rgb() -> (u8, u8, u8) {
+    (rng::below(256) as u8, rng::below(256) as u8, rng::below(256) as u8)
+}
+
+// Hue in degrees [0, 360), saturation and lightness in percent
+pub fn hsl() -> (u16, u8, u8) {
+    let (r, g, b) = rgb();
+    to_hsl(r, g, b)
+}
+
+// Cyan, magenta, yellow and key (black) in percent
+pub fn cmyk() -> (u8, u8, u8, u8) {
+    let (r, g, b) = rgb();
+    to_cmyk(r, g, b)
+}
+
+pub fn to_hsl(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let l = (max + min) / 2.0;
+    if delta == 0.0 {
+        return (0, 0, (l * 100.0).round() as u8);
+    }
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    ((h.round() as u16) % 360, (s * 100.0).round() as u8, (l * 100.0).round() as u8)
+}
+
+pub fn to_cmyk(r: u8, g: u8, b: u8) -> (u8, u8, u8, u8) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let k = 1.0 - r.max(g).max(b);
+    if k == 1.0 {
+        return (0, 0, 0, 100);
+    }
+    let channel = |x: f64| ((1.0 - x - k) / (1.0 - k) * 100.0).round() as u8;
+    (channel(r), channel(g), channel(b), (k * 100.0).round() as u8)
+}
+
+fn from_hsl(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match (h / 60.0) as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+// WCAG relative luminance
+fn luminance(r: u8, g: u8, b: u8) -> f64 {
+    let linear = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+fn contrast_with_white(r: u8, g: u8, b: u8) -> f64 {
+    1.05 / (luminance(r, g, b) + 0.05)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let d = |x: u8, y: u8| (x as f64 - y as f64).powi(2);
+    (d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)).sqrt()
+}
+
+// `n` distinct colors as "#rrggbb", each with at least PALETTE_CONTRAST against
+// white. Candidates are drawn in HSL so that saturated mid-tones dominate.
+pub fn palette(n: usize) -> Vec<String> {
+    let mut colors: Vec<(u8, u8, u8)> = Vec::with_capacity(n);
+    let mut min_distance = PALETTE_DISTANCE;
+    let mut misses = 0;
+    while colors.len() < n {
+        let h = rng::below(360) as f64;
+        let s = (45 + rng::below(46)) as f64 / 100.0;
+        let l = (20 + rng::below(31)) as f64 / 100.0;
+        let color = from_hsl(h, s, l);
+        let readable = contrast_with_white(color.0, color.1, color.2) >= PALETTE_CONTRAST;
+        let distinct = colors.iter().all(|&other| distance(color, other) >= min_distance && other != color);
+        if readable && distinct {
+            colors.push(color);
+            continue;
+        }
+        misses += 1;
+        if misses == 100 {
+            misses = 0;
+            min_distance /= 1.5;
+        }
+    }
+    colors.into_iter().map(|(r, g, b)| format!("#{:02x}{:02x}{:02x}", r, g, b)).collect()
+}
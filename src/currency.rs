@@ -0,0 +1,43 @@
+// Prices in the smallest unit a currency actually uses.
+//
+// Amounts are exchanged as scaled integers, the way DuckDB stores DECIMAL
+// values: 1234 at scale 2 is 12.34.
+
+use super::rng;
+
+// ISO 4217 currencies without minor units
+static ZERO_DECIMALS: &[&str] = &[
+    "BIF", "BYR", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "VND", "VUV", "XAF",
+    "XOF", "XPF",
+];
+
+// ISO 4217 currencies with three decimals
+static THREE_DECIMALS: &[&str] = &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+
+// Number of decimals of a currency code known to the fakeit data set
+pub fn minor_units(code: &str) -> Option<u32> {
+    let code = code.to_ascii_uppercase();
+    if !fakeit::data::currency::SHORT.contains(&code.as_str()) {
+        return None;
+    }
+    if ZERO_DECIMALS.contains(&code.as_str()) {
+        Some(0)
+    } else if THREE_DECIMALS.contains(&code.as_str()) {
+        Some(3)
+    } else {
+        Some(2)
+    }
+}
+
+// Random amount between `min` and `max` at `scale`, rounded to whole minor
+// units of a currency with `decimals` decimals (at most `scale`). None when no
+// such amount lies within the range.
+pub fn price(min: i64, max: i64, scale: u32, decimals: u32) -> Option<i64> {
+    let step = 10i64.pow(scale - decimals);
+    let low = min.div_euclid(step) + if min.rem_euclid(step) == 0 { 0 } else { 1 };
+    let high = max.div_euclid(step);
+    if low > high {
+        return None;
+    }
+    Some(rng::between(low, high) * step)
+}
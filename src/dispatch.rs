@@ -0,0 +1,321 @@
+// The fakeit(generator, args...) scalar: calls the function that `generator`
+// names with `args` and returns its values as text.
+//
+//   SELECT fakeit('address.city'), fakeit('int', 1, 10), fakeit('name_full', user_id)
+//
+// DuckDB fixes the return type of a scalar function when the extension
+// registers it, and a bind callback cannot change it, so every value comes
+// back as VARCHAR, rendered as DuckDB would cast it (see text.rs). The rows of
+// a chunk are grouped by name; each group becomes a chunk of its own, with the
+// arguments converted to the parameter types of the overload that fits them
+// best, and goes to the invoke function of the named function as it is listed
+// in FUNCTIONS.
+
+use duckdb::core::{DataChunkHandle, FlatVector, Inserter, ListVector, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vtab::arrow::WritableVector;
+use libduckdb_sys as ffi;
+use std::error::Error;
+
+use super::functions::{Function, Overload, Type};
+use super::{args, number, registry, text};
+
+pub unsafe fn invoke(input: &mut DataChunkHandle, output: &mut dyn WritableVector) -> Result<(), Box<dyn Error>> {
+    let mut output_vec = output.flat_vector();
+    let arguments: Vec<_> =
+        (1..input.num_columns()).map(|col| ffi::duckdb_data_chunk_get_vector(input.get_ptr(), col as u64)).collect();
+
+    // Names usually repeat from row to row, and often hold for the whole chunk
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (i, name) in args::strings(input, 0).into_iter().enumerate() {
+        match name {
+            Some(name) => match groups.iter_mut().find(|(group, _)| *group == name) {
+                Some((_, rows)) => rows.push(i),
+                None => groups.push((name, vec![i])),
+            },
+            None => output_vec.set_null(i),
+        }
+    }
+
+    for (name, rows) in groups {
+        let function = registry::function(&name)
+            .filter(|function| function.invoke.is_some())
+            .ok_or_else(|| format!("fakeit: unknown generator '{}'", name))?;
+        let &(params, ret) = overload(function, &arguments)?;
+
+        let types: Vec<LogicalTypeHandle> = params
+            .iter()
+            .zip(&arguments)
+            .map(|(param, &argument)| match param {
+                Type::Any => type_of(argument),
+                _ => param.handle(),
+            })
+            .collect();
+        let mut chunk = DataChunkHandle::new(&types);
+        let whole = rows.len() == input.len();
+        for (col, (&param, &source)) in params.iter().zip(&arguments).enumerate() {
+            let target = ffi::duckdb_data_chunk_get_vector(chunk.get_ptr(), col as u64);
+            if matches!(param, Type::Any) || same(param, source) {
+                if whole {
+                    ffi::duckdb_vector_reference_vector(target, source);
+                } else {
+                    copy(source, target, &rows);
+                }
+            } else {
+                convert(source, &rows, target, param)?;
+            }
+        }
+        chunk.set_len(rows.len());
+
+        let result = DataChunkHandle::new(&[ret.handle()]);
+        let mut result_vec = ffi::duckdb_data_chunk_get_vector(result.get_ptr(), 0);
+        (function.invoke.unwrap())(&(), &mut chunk, &mut result_vec)?;
+        for (k, &i) in rows.iter().enumerate() {
+            match text::render(result_vec, ret, k) {
+                Some(value) => output_vec.insert(i, value.as_str()),
+                None => output_vec.set_null(i),
+            }
+        }
+    }
+    Ok(())
+}
+
+// The overload of `function` that takes the types of `arguments` with the
+// fewest conversions: a parameter of the same type costs nothing, one that
+// the argument converts to 1 and ANY 2. The first one listed wins a tie.
+unsafe fn overload(function: &'static Function, arguments: &[ffi::duckdb_vector]) -> Result<&'static Overload, String> {
+    let mut best: Option<(usize, &Overload)> = None;
+    for overload in function.overloads.iter().filter(|(params, _)| params.len() == arguments.len()) {
+        let costs: Option<Vec<usize>> = overload
+            .0
+            .iter()
+            .zip(arguments)
+            .map(|(&param, &argument)| match param {
+                _ if same(param, argument) => Some(0),
+                Type::Any => Some(2),
+                _ => converts(argument, param).then_some(1),
+            })
+            .collect();
+        if let Some(cost) = costs.map(|costs| costs.iter().sum()) {
+            if best.is_none_or(|(best, _)| cost < best) {
+                best = Some((cost, overload));
+            }
+        }
+    }
+    best.map(|(_, overload)| overload).ok_or_else(|| {
+        let mut takes: Vec<String> = function.overloads.iter().map(|(params, _)| list(params.iter().map(|t| t.name()))).collect();
+        takes.dedup();
+        format!(
+            "fakeit: {} does not take {}; cast the arguments to one of {}",
+            function.name,
+            list(arguments.iter().map(|&argument| name(argument))),
+            takes.join(", ")
+        )
+    })
+}
+
+fn list(names: impl Iterator<Item = String>) -> String {
+    format!("({})", names.collect::<Vec<_>>().join(", "))
+}
+
+// Types are read off vectors rather than logical types, since the C API has
+// no way to get the element type of a LIST type but does have its child vector
+unsafe fn type_of(vector: ffi::duckdb_vector) -> LogicalTypeHandle {
+    FlatVector::from(vector).logical_type()
+}
+
+// Whether `vector` holds values of type `t`
+unsafe fn same(t: Type, vector: ffi::duckdb_vector) -> bool {
+    let handle = type_of(vector);
+    let id = handle.raw_id();
+    match t {
+        Type::Decimal(width, scale) => {
+            id == ffi::DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL && handle.decimal_width() == width && handle.decimal_scale() == scale
+        }
+        Type::List(child) => id == ffi::DUCKDB_TYPE_DUCKDB_TYPE_LIST && same(*child, ffi::duckdb_list_vector_get_child(vector)),
+        Type::Struct(fields) => {
+            id == ffi::DUCKDB_TYPE_DUCKDB_TYPE_STRUCT
+                && handle.num_children() == fields.len()
+                && fields.iter().enumerate().all(|(i, &(name, t))| {
+                    handle.child_name(i) == name && same(t, ffi::duckdb_struct_vector_get_child(vector, i as u64))
+                })
+        }
+        Type::Any | Type::Table => false,
+        _ => id == t.handle().raw_id(),
+    }
+}
+
+// Range of the values of an integer type, and the number of digits it takes
+fn integer_range(id: u32) -> Option<(i128, i128, u8)> {
+    Some(match id {
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_TINYINT => (i8::MIN as i128, i8::MAX as i128, 3),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT => (i16::MIN as i128, i16::MAX as i128, 5),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER => (i32::MIN as i128, i32::MAX as i128, 10),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_BIGINT => (i64::MIN as i128, i64::MAX as i128, 19),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT => (i128::MIN, i128::MAX, 39),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_UTINYINT => (0, u8::MAX as i128, 3),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_USMALLINT => (0, u16::MAX as i128, 5),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_UINTEGER => (0, u32::MAX as i128, 10),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_UBIGINT => (0, u64::MAX as i128, 20),
+        _ => return None,
+    })
+}
+
+// Whether the values of `vector` convert to `t` without losing any:
+// integers to wider integers, decimals and DOUBLE, decimals to wider decimals
+// and DOUBLE, FLOAT to DOUBLE, DATE to TIMESTAMP, lists by their elements and
+// NULL to anything
+unsafe fn converts(vector: ffi::duckdb_vector, t: Type) -> bool {
+    let handle = type_of(vector);
+    let id = handle.raw_id();
+    if id == ffi::DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL {
+        return true;
+    }
+    let digits = |t: Type| match t {
+        Type::Decimal(width, scale) => Some((width - scale, scale)),
+        _ => None,
+    };
+    if let Some((min, max, source_digits)) = integer_range(id) {
+        return match t {
+            Type::Double => true,
+            Type::Decimal(..) => digits(t).is_some_and(|(whole, _)| whole >= source_digits),
+            _ => integer_range(t.handle().raw_id()).is_some_and(|(to_min, to_max, _)| to_min <= min && max <= to_max),
+        };
+    }
+    match (id, t) {
+        (ffi::DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL, Type::Double) => true,
+        (ffi::DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL, Type::Decimal(..)) => digits(t).is_some_and(|(whole, scale)| {
+            whole >= handle.decimal_width() - handle.decimal_scale() && scale >= handle.decimal_scale()
+        }),
+        (ffi::DUCKDB_TYPE_DUCKDB_TYPE_FLOAT, Type::Double) => true,
+        (ffi::DUCKDB_TYPE_DUCKDB_TYPE_DATE, Type::Timestamp) => true,
+        (ffi::DUCKDB_TYPE_DUCKDB_TYPE_LIST, Type::List(child)) => {
+            let elements = ffi::duckdb_list_vector_get_child(vector);
+            same(*child, elements) || converts(elements, *child)
+        }
+        _ => false,
+    }
+}
+
+// Rows `rows` of `source` into the first rows of `target`, of the same type
+unsafe fn copy(source: ffi::duckdb_vector, target: ffi::duckdb_vector, rows: &[usize]) {
+    let selection = ffi::duckdb_create_selection_vector(rows.len() as u64);
+    let indexes = std::slice::from_raw_parts_mut(ffi::duckdb_selection_vector_get_data_ptr(selection), rows.len());
+    for (index, &row) in indexes.iter_mut().zip(rows) {
+        *index = row as u32;
+    }
+    ffi::duckdb_vector_copy_sel(source, target, selection, rows.len() as u64, 0, 0);
+    ffi::duckdb_destroy_selection_vector(selection);
+}
+
+// Rows `rows` of `source` converted to `t` into the first rows of `target`,
+// for a conversion that `converts` allows
+unsafe fn convert(source: ffi::duckdb_vector, rows: &[usize], target: ffi::duckdb_vector, t: Type) -> Result<(), String> {
+    let source_vec = FlatVector::from(source);
+    let mut target_vec = FlatVector::from(target);
+    let source_type = source_vec.logical_type();
+    let id = source_type.raw_id();
+
+    if let Type::List(child) = t {
+        let list = ListVector::from(source);
+        let mut target_list = ListVector::from(target);
+        let mut elements = Vec::new();
+        for (k, &row) in rows.iter().enumerate() {
+            if id == ffi::DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL || source_vec.row_is_null(row as u64) {
+                target_list.set_null(k);
+                continue;
+            }
+            let (offset, length) = list.get_entry(row);
+            target_list.set_entry(k, elements.len(), length);
+            elements.extend(offset..offset + length);
+        }
+        ffi::duckdb_list_vector_reserve(target, elements.len() as u64);
+        target_list.set_len(elements.len());
+        let source_elements = ffi::duckdb_list_vector_get_child(source);
+        let target_elements = ffi::duckdb_list_vector_get_child(target);
+        if same(*child, source_elements) {
+            copy(source_elements, target_elements, &elements);
+            return Ok(());
+        }
+        return convert(source_elements, &elements, target_elements, *child);
+    }
+
+    for (k, &row) in rows.iter().enumerate() {
+        if id == ffi::DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL || source_vec.row_is_null(row as u64) {
+            target_vec.set_null(k);
+            continue;
+        }
+        let at = |size: usize| source_vec.as_mut_ptr::<u8>().add(row * size);
+        // Integers and decimals as a count of 10^-scale, other numbers as f64
+        let (units, scale, float) = match id {
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_FLOAT => (0, 0, *(at(4) as *const f32) as f64),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_DATE => (*(at(4) as *const i32) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL => {
+                let units = match source_type.decimal_width() {
+                    0..=4 => *(at(2) as *const i16) as i128,
+                    5..=9 => *(at(4) as *const i32) as i128,
+                    10..=18 => *(at(8) as *const i64) as i128,
+                    _ => number::from_hugeint(*(at(16) as *const ffi::duckdb_hugeint)),
+                };
+                (units, source_type.decimal_scale(), 0.0)
+            }
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_TINYINT => (*(at(1) as *const i8) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_SMALLINT => (*(at(2) as *const i16) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_INTEGER => (*(at(4) as *const i32) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_BIGINT => (*(at(8) as *const i64) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_HUGEINT => (number::from_hugeint(*(at(16) as *const ffi::duckdb_hugeint)), 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_UTINYINT => (*at(1) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_USMALLINT => (*(at(2) as *const u16) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_UINTEGER => (*(at(4) as *const u32) as i128, 0, 0.0),
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_UBIGINT => (*(at(8) as *const u64) as i128, 0, 0.0),
+            _ => return Err(format!("fakeit: cannot convert {} to {}", name(source), t.name())),
+        };
+        let float = match id {
+            ffi::DUCKDB_TYPE_DUCKDB_TYPE_FLOAT => float,
+            _ => units as f64 / 10f64.powi(scale as i32),
+        };
+        match t {
+            Type::Double => target_vec.as_mut_slice::<f64>()[k] = float,
+            Type::Timestamp => target_vec.as_mut_slice::<i64>()[k] = (units * super::kind::MICROS_PER_DAY) as i64,
+            Type::Integer => target_vec.as_mut_slice::<i32>()[k] = units as i32,
+            Type::Bigint => target_vec.as_mut_slice::<i64>()[k] = units as i64,
+            Type::UBigint => target_vec.as_mut_slice::<u64>()[k] = units as u64,
+            Type::UTinyint => target_vec.as_mut_slice::<u8>()[k] = units as u8,
+            Type::USmallint => target_vec.as_mut_slice::<u16>()[k] = units as u16,
+            Type::Hugeint => target_vec.as_mut_slice::<ffi::duckdb_hugeint>()[k] = number::to_hugeint(units),
+            Type::Decimal(width, to_scale) => {
+                let units = units * 10i128.pow((to_scale - scale) as u32);
+                match width {
+                    0..=4 => target_vec.as_mut_slice::<i16>()[k] = units as i16,
+                    5..=9 => target_vec.as_mut_slice::<i32>()[k] = units as i32,
+                    10..=18 => target_vec.as_mut_slice::<i64>()[k] = units as i64,
+                    _ => target_vec.as_mut_slice::<ffi::duckdb_hugeint>()[k] = number::to_hugeint(units),
+                }
+            }
+            _ => return Err(format!("fakeit: cannot convert {} to {}", name(source), t.name())),
+        }
+    }
+    Ok(())
+}
+
+// The type of `vector` as DuckDB spells it, for error messages
+unsafe fn name(vector: ffi::duckdb_vector) -> String {
+    let handle = type_of(vector);
+    match handle.raw_id() {
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_SQLNULL => "NULL".to_string(),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL => {
+            format!("DECIMAL({},{})", handle.decimal_width(), handle.decimal_scale())
+        }
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_LIST => format!("{}[]", name(ffi::duckdb_list_vector_get_child(vector))),
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_STRUCT => {
+            let fields: Vec<String> = (0..handle.num_children())
+                .map(|i| format!("{} {}", handle.child_name(i), name(ffi::duckdb_struct_vector_get_child(vector, i as u64))))
+                .collect();
+            format!("STRUCT({})", fields.join(", "))
+        }
+        _ => match handle.try_id() {
+            Ok(LogicalTypeId::TimestampTZ) => "TIMESTAMP WITH TIME ZONE".to_string(),
+            Ok(id) => format!("{:?}", id).to_uppercase(),
+            Err(_) => "an unsupported type".to_string(),
+        },
+    }
+}
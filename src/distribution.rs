@@ -0,0 +1,263 @@
+// Samplers for the statistical distribution functions.
+//
+// Every sampler draws from the shared stream through `rng::unit`, so seeding
+// and keyed generation work for them like for any other generator. The
+// parameters come straight from SQL and are checked on every call; an invalid
+// one is reported as a message that the scalar prefixes with its name.
+
+use super::rng;
+
+// Uniform float in (0, 1], safe to take the logarithm of
+fn open_unit() -> f64 {
+    1.0 - rng::unit()
+}
+
+fn positive(name: &str, value: f64) -> Result<(), String> {
+    if value > 0.0 && value.is_finite() {
+        Ok(())
+    } else {
+        Err(format!("{} must be positive, got {}", name, value))
+    }
+}
+
+fn probability(name: &str, value: f64) -> Result<(), String> {
+    if (0.0..=1.0).contains(&value) {
+        Ok(())
+    } else {
+        Err(format!("{} must be between 0 and 1, got {}", name, value))
+    }
+}
+
+// Uniform float in [min, max)
+pub fn uniform(min: f64, max: f64) -> Result<f64, String> {
+    if !(min.is_finite() && max.is_finite()) {
+        return Err(format!("min and max must be finite, got {} and {}", min, max));
+    }
+    if min > max {
+        return Err("min must not be greater than max".to_string());
+    }
+    Ok(min + (max - min) * rng::unit())
+}
+
+// Standard normal variate (Box-Muller)
+pub fn standard_normal() -> f64 {
+    (-2.0 * open_unit().ln()).sqrt() * (std::f64::consts::TAU * rng::unit()).cos()
+}
+
+pub fn normal(mean: f64, sd: f64) -> Result<f64, String> {
+    if !(sd >= 0.0 && sd.is_finite()) {
+        return Err(format!("sd must not be negative, got {}", sd));
+    }
+    Ok(mean + sd * standard_normal())
+}
+
+// exp of a normal variate with mean `mu` and standard deviation `sigma`
+pub fn lognormal(mu: f64, sigma: f64) -> Result<f64, String> {
+    if !(sigma >= 0.0 && sigma.is_finite()) {
+        return Err(format!("sigma must not be negative, got {}", sigma));
+    }
+    Ok((mu + sigma * standard_normal()).exp())
+}
+
+pub fn exponential(rate: f64) -> Result<f64, String> {
+    positive("rate", rate)?;
+    Ok(-open_unit().ln() / rate)
+}
+
+pub fn poisson(lambda: f64) -> Result<i64, String> {
+    if !(lambda >= 0.0 && lambda.is_finite()) {
+        return Err(format!("lambda must not be negative, got {}", lambda));
+    }
+    Ok(if lambda < 30.0 { poisson_small(lambda) } else { poisson_ptrs(lambda) })
+}
+
+// Knuth's multiplication method, O(lambda)
+fn poisson_small(lambda: f64) -> i64 {
+    let limit = (-lambda).exp();
+    let mut k = 0;
+    let mut product = rng::unit();
+    while product >= limit {
+        k += 1;
+        product *= rng::unit();
+    }
+    k
+}
+
+// Hörmann's transformed rejection with squeeze (PTRS), O(1) for large lambda
+fn poisson_ptrs(lambda: f64) -> i64 {
+    let slam = lambda.sqrt();
+    let loglam = lambda.ln();
+    let b = 0.931 + 2.53 * slam;
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let vr = 0.9277 - 3.6224 / (b - 2.0);
+    loop {
+        let u = rng::unit() - 0.5;
+        let v = rng::unit();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+        if us >= 0.07 && v <= vr {
+            return k as i64;
+        }
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+        if v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln() <= -lambda + k * loglam - ln_gamma(k + 1.0) {
+            return k as i64;
+        }
+    }
+}
+
+// ln Γ(x) for x > 0 (Lanczos, g = 7)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Reflection formula
+        let pi = std::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+    0.5 * std::f64::consts::TAU.ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+pub fn binomial(n: i64, p: f64) -> Result<i64, String> {
+    if n < 0 {
+        return Err(format!("n must not be negative, got {}", n));
+    }
+    probability("p", p)?;
+    // Split large n by the order statistics of uniforms (Knuth 3.4.1): the
+    // a-th smallest of n uniforms is Beta(a, n + 1 - a), and it tells how many
+    // of them fall below p on one side
+    let (mut n, mut p, mut successes) = (n, p, 0);
+    while n > 64 {
+        let a = 1 + n / 2;
+        let b = n + 1 - a;
+        let x = beta_variate(a as f64, b as f64);
+        if x >= p {
+            n = a - 1;
+            p /= x;
+        } else {
+            successes += a;
+            n = b - 1;
+            p = (p - x) / (1.0 - x);
+        }
+    }
+    Ok(successes + (0..n).filter(|_| rng::unit() < p).count() as i64)
+}
+
+// Rank in 1..=n with probability proportional to 1 / rank^s, by Hörmann and
+// Derflinger's rejection-inversion, so that n may be huge
+pub fn zipf(n: i64, s: f64) -> Result<i64, String> {
+    if n < 1 {
+        return Err(format!("n must be at least 1, got {}", n));
+    }
+    if !(s >= 0.0 && s.is_finite()) {
+        return Err(format!("s must not be negative, got {}", s));
+    }
+    // h(x) = x^-s and its integral H, written to stay exact near s = 1
+    let h = |x: f64| (-s * x.ln()).exp();
+    let h_integral = |x: f64| {
+        let log_x = x.ln();
+        expm1_over_x((1.0 - s) * log_x) * log_x
+    };
+    let h_integral_inverse = |x: f64| {
+        let t = (x * (1.0 - s)).max(-1.0);
+        (ln1p_over_x(t) * x).exp()
+    };
+
+    let h_integral_x1 = h_integral(1.5) - 1.0;
+    let h_integral_n = h_integral(n as f64 + 0.5);
+    let squeeze = 2.0 - h_integral_inverse(h_integral(2.5) - h(2.0));
+    loop {
+        let u = h_integral_n + rng::unit() * (h_integral_x1 - h_integral_n);
+        let x = h_integral_inverse(u);
+        let k = ((x + 0.5) as i64).clamp(1, n);
+        if k as f64 - x <= squeeze || u >= h_integral(k as f64 + 0.5) - h(k as f64) {
+            return Ok(k);
+        }
+    }
+}
+
+// (e^x - 1) / x, 1 at 0
+fn expm1_over_x(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.exp_m1() / x
+    } else {
+        1.0 + x / 2.0
+    }
+}
+
+// ln(1 + x) / x, 1 at 0
+fn ln1p_over_x(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        x.ln_1p() / x
+    } else {
+        1.0 - x / 2.0
+    }
+}
+
+// Values of at least `scale`, with tail index `shape`
+pub fn pareto(scale: f64, shape: f64) -> Result<f64, String> {
+    positive("scale", scale)?;
+    positive("shape", shape)?;
+    Ok(scale / open_unit().powf(1.0 / shape))
+}
+
+pub fn gamma(shape: f64, scale: f64) -> Result<f64, String> {
+    positive("shape", shape)?;
+    positive("scale", scale)?;
+    Ok(gamma_variate(shape) * scale)
+}
+
+// Gamma(shape, 1) by Marsaglia and Tsang, boosted for shape < 1
+fn gamma_variate(shape: f64) -> f64 {
+    if shape < 1.0 {
+        return gamma_variate(shape + 1.0) * open_unit().powf(1.0 / shape);
+    }
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let x = standard_normal();
+        let v = (1.0 + c * x).powi(3);
+        if v <= 0.0 {
+            continue;
+        }
+        let u = open_unit();
+        if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
+pub fn beta(alpha: f64, beta: f64) -> Result<f64, String> {
+    positive("alpha", alpha)?;
+    positive("beta", beta)?;
+    Ok(beta_variate(alpha, beta))
+}
+
+fn beta_variate(alpha: f64, beta: f64) -> f64 {
+    let x = gamma_variate(alpha);
+    let y = gamma_variate(beta);
+    x / (x + y)
+}
+
+// 1 with probability p, otherwise 0
+pub fn bernoulli(p: f64) -> Result<i64, String> {
+    probability("p", p)?;
+    Ok((rng::unit() < p) as i64)
+}
@@ -0,0 +1,847 @@
+// The fakeit_fill table function: rows that fit an existing table.
+//
+//   INSERT INTO customers SELECT * FROM fakeit_fill('customers', rows := 1000)
+//
+// Bind reads the columns and constraints of the table from the catalog (see
+// catalog.rs) and gives every column a source of values, chosen by its type
+// and name: an `email` column gets contact_email, an `age` column numbers
+// between 18 and 90. The constraints narrow the sources down:
+//
+// - NOT NULL: only columns of types that cannot be generated are ever NULL
+// - CHECK: comparisons with constants, BETWEEN and IN (see check.rs) bound
+//   the range or the set of values; any other check is an error
+// - PRIMARY KEY and UNIQUE: a single integer key counts up from the largest
+//   value in the table; other keys are drawn again until they are new, see
+//   unique.rs
+// - FOREIGN KEY: values are picked from the rows of the referenced table
+//
+// Dates and timestamps fall in fixed ranges rather than ranges relative to
+// today, so that seeded output does not drift over time.
+
+use duckdb::core::{DataChunkHandle, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab};
+use duckdb::Connection;
+use libduckdb_sys as ffi;
+use std::cmp::Ordering;
+use std::error::Error;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Mutex;
+
+use super::catalog::{quote, Catalog};
+use super::check::{self, Condition};
+use super::functions::{self, Parameters, Type};
+use super::kind::{self, Kind, Value, MICROS_PER_DAY};
+use super::number;
+use super::registry::{self, Generator};
+use super::rng;
+use super::unique::Issued;
+use super::uuid;
+
+// Days since 1970-01-01 of the default date range, 2020-01-01 to 2025-12-31,
+// and of birthdates, 1935-01-01 to 2006-12-31 as in fakeit_persons
+const DATE_MIN: i128 = 18262;
+const DATE_MAX: i128 = 20453;
+const BIRTHDATE_MIN: i128 = -12784;
+const BIRTHDATE_MAX: i128 = 13513;
+
+// Generators for VARCHAR columns, by a word anywhere in the column name or,
+// for the short and ambiguous ones, at the end of it. Names are split into
+// words (see `words`), and an entry matches a run of whole words, so
+// `first_name`, `firstName` and `firstname` match "firstname" while
+// `membership` does not match "ip". The first match wins.
+static TEXT_COLUMNS: &[(&str, bool, &str)] = &[
+    ("email", true, "contact_email"),
+    ("phone", true, "contact_phone"),
+    ("mobile", true, "contact_phone"),
+    ("firstname", true, "name_first"),
+    ("givenname", true, "name_first"),
+    ("lastname", true, "name_last"),
+    ("surname", true, "name_last"),
+    ("familyname", true, "name_last"),
+    ("username", true, "internet_username"),
+    ("login", true, "internet_username"),
+    ("password", true, "password_generate"),
+    ("company", true, "company_company"),
+    ("employer", true, "company_company"),
+    ("jobtitle", true, "job_title"),
+    ("street", true, "address_street"),
+    ("city", true, "address_city"),
+    ("country", true, "address_country"),
+    ("zip", true, "address_zip"),
+    ("postalcode", true, "address_zip"),
+    ("postcode", true, "address_zip"),
+    ("ipaddress", false, "internet_ipv4_address"),
+    ("address", false, "address_street"),
+    ("state", false, "address_state"),
+    ("domain", false, "internet_domain_name"),
+    ("ip", false, "internet_ipv4_address"),
+    ("url", false, "image_url"),
+    ("website", false, "image_url"),
+    ("uuid", false, "uuid_v4"),
+    ("guid", false, "uuid_v4"),
+    ("currency", false, "currency_short"),
+    ("color", false, "color_full"),
+    ("colour", false, "color_full"),
+    ("language", false, "language_random"),
+    ("gender", false, "person_gender"),
+    ("ssn", false, "person_ssn"),
+    ("title", false, "job_title"),
+    ("name", false, "name_full"),
+    ("description", false, "words_sentence"),
+    ("comment", false, "words_sentence"),
+    ("notes", false, "words_sentence"),
+    ("bio", false, "words_sentence"),
+    ("text", false, "words_sentence"),
+];
+
+enum Source {
+    // Types that cannot be generated
+    Null,
+    // The row number (from 0) plus the start
+    Sequence(i128),
+    Int(i128, i128),
+    Float(f64, f64),
+    Text(Generator),
+    Uuid,
+    Choice(Vec<Value>),
+    // Filled in with the rest of a foreign key
+    Reference,
+}
+
+struct Column {
+    name: String,
+    // As the catalog spells it
+    data_type: String,
+    // None for a type that cannot be generated
+    kind: Option<Kind>,
+    source: Source,
+}
+
+struct ForeignKey {
+    columns: Vec<usize>,
+    // Rows of the referenced table, in the order of `columns`
+    rows: Vec<Vec<Value>>,
+}
+
+struct UniqueKey {
+    columns: Vec<usize>,
+    // Keys already in the table
+    existing: Vec<String>,
+}
+
+pub struct FillBindData {
+    rows: u64,
+    columns: Vec<Column>,
+    foreign_keys: Vec<ForeignKey>,
+    unique_keys: Vec<UniqueKey>,
+}
+
+pub struct FillInitData {
+    next_row: AtomicU64,
+    // Bind column index of every output column, in output order
+    projection: Vec<usize>,
+    // Keys issued so far, one per unique key
+    issued: Mutex<Vec<Issued>>,
+}
+
+pub struct FillTable;
+
+impl Parameters for FillTable {
+    const PARAMETERS: &'static [Type] = &[Type::Varchar];
+
+    fn named_parameters() -> Vec<(&'static str, Type)> {
+        vec![("rows", Type::Bigint)]
+    }
+
+    fn register(con: &Connection, name: &str, catalog: &Catalog) -> duckdb::Result<()> {
+        con.register_table_function_with_extra_info::<Self, _>(name, catalog)
+    }
+}
+
+impl VTab for FillTable {
+    type InitData = FillInitData;
+    type BindData = FillBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn Error>> {
+        let name = bind.get_parameter(0).to_string();
+        let rows = match bind.get_named_parameter("rows") {
+            Some(rows) => rows.to_int64(),
+            None => return Err("fakeit_fill: the rows parameter is required".into()),
+        };
+        if rows < 0 {
+            return Err("fakeit_fill: the number of rows must not be negative".into());
+        }
+
+        let catalog = unsafe { &*bind.get_extra_info::<Catalog>() };
+        let table = Table::resolve(catalog, &name).map_err(|e| format!("fakeit_fill: {}", e))?;
+        let (columns, foreign_keys, unique_keys) =
+            plan(catalog, &table, rows as u64).map_err(|e| format!("fakeit_fill: {}", e))?;
+        for column in &columns {
+            let logical_type = match column.kind {
+                Some(kind) => kind.logical_type(),
+                None => LogicalTypeHandle::from(LogicalTypeId::Varchar),
+            };
+            bind.add_result_column(&column.name, logical_type);
+        }
+        bind.set_cardinality(rows as u64, true);
+        Ok(FillBindData {
+            rows: rows as u64,
+            columns,
+            foreign_keys,
+            unique_keys,
+        })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn Error>> {
+        let projection = init.get_column_indices().into_iter().map(|i| i as usize).collect();
+        Ok(FillInitData {
+            next_row: AtomicU64::new(0),
+            projection,
+            issued: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn Error>> {
+        let bind_data = func.get_bind_data();
+        let init_data = func.get_init_data();
+
+        let chunk_size = unsafe { ffi::duckdb_vector_size() };
+        let start = init_data.next_row.fetch_add(chunk_size, atomic::Ordering::Relaxed);
+        if start >= bind_data.rows {
+            output.set_len(0);
+            return Ok(());
+        }
+        let len = (bind_data.rows - start).min(chunk_size) as usize;
+
+        let _stream = rng::lock();
+        let mut issued = init_data.issued.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if issued.is_empty() {
+            for key in &bind_data.unique_keys {
+                let mut keys = Issued::default();
+                key.existing.iter().for_each(|existing| keys.insert(existing.clone()));
+                issued.push(keys);
+            }
+        }
+
+        // Whole rows are drawn even when only some columns are read, so that
+        // the constraints between columns hold for a projection too
+        let mut rows = Vec::with_capacity(len);
+        for i in 0..len {
+            let row_number = (start as usize + i) as i128;
+            let mut row: Vec<Value> = bind_data.columns.iter().map(|c| draw(&c.source, row_number)).collect();
+            for key in &bind_data.foreign_keys {
+                pick_reference(key, &mut row);
+            }
+            for (key, issued) in bind_data.unique_keys.iter().zip(issued.iter_mut()) {
+                make_unique(bind_data, key, issued, &mut row, row_number)?;
+            }
+            rows.push(row);
+        }
+
+        for (col, &index) in init_data.projection.iter().enumerate() {
+            // Row-id and other virtual columns are never read
+            let Some(column) = bind_data.columns.get(index) else {
+                continue;
+            };
+            let mut vector = output.flat_vector(col);
+            for (i, row) in rows.iter().enumerate() {
+                match column.kind {
+                    Some(kind) => kind::write(&mut vector, kind, i, &row[index]),
+                    None => vector.set_null(i),
+                }
+            }
+        }
+        output.set_len(len);
+        Ok(())
+    }
+
+    fn supports_pushdown() -> bool {
+        true
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        functions::parameters::<Self>()
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        functions::named_parameters::<Self>()
+    }
+}
+
+// A table found in the catalog, with its name as SQL
+struct Table {
+    database: String,
+    schema: String,
+    name: String,
+}
+
+impl Table {
+    // Find a table named like in SQL: `table`, `schema.table`, `database.table`
+    // or `database.schema.table`, with optional double quotes. The catalog
+    // connection does not share the caller's current database and schema, so a
+    // name is looked up in all of them and has to be unambiguous.
+    fn resolve(catalog: &Catalog, name: &str) -> Result<Table, String> {
+        let parts = split_name(name).ok_or_else(|| format!("invalid table name '{}'", name))?;
+        // (database, schema) to try, "" for any
+        let candidates: Vec<(&str, &str)> = match parts.as_slice() {
+            [_] => vec![("", "")],
+            [first, _] => vec![("", first.as_str()), (first.as_str(), "")],
+            [database, schema, _] => vec![(database.as_str(), schema.as_str())],
+            _ => return Err(format!("invalid table name '{}'", name)),
+        };
+        let table = parts.last().unwrap();
+        let mut found: Vec<Table> = Vec::new();
+        for (database, schema) in candidates {
+            let rows = catalog.query(
+                "SELECT database_name, schema_name, table_name FROM duckdb_tables() \
+                 WHERE lower(table_name) = lower($1) \
+                 AND ($2 = '' OR lower(database_name) = lower($2)) \
+                 AND ($3 = '' OR lower(schema_name) = lower($3)) \
+                 ORDER BY database_name, schema_name",
+                &[table, database, schema],
+            )?;
+            for row in rows {
+                let mut row = row.into_iter().map(Option::unwrap_or_default);
+                let table = Table {
+                    database: row.next().unwrap_or_default(),
+                    schema: row.next().unwrap_or_default(),
+                    name: row.next().unwrap_or_default(),
+                };
+                if !found.iter().any(|t| t.sql() == table.sql()) {
+                    found.push(table);
+                }
+            }
+        }
+        match found.len() {
+            0 => Err(format!(
+                "table '{}' does not exist; temporary tables and tables created in an open transaction \
+                 cannot be filled",
+                name
+            )),
+            1 => Ok(found.pop().unwrap()),
+            _ => {
+                let names: Vec<String> = found.iter().map(|t| format!("{}.{}.{}", t.database, t.schema, t.name)).collect();
+                Err(format!("table name '{}' is ambiguous, qualify it as one of {}", name, names.join(", ")))
+            }
+        }
+    }
+
+    fn sql(&self) -> String {
+        format!("{}.{}.{}", quote(&self.database), quote(&self.schema), quote(&self.name))
+    }
+
+    // Another table of the same schema
+    fn sibling(&self, name: &str) -> Table {
+        Table {
+            database: self.database.clone(),
+            schema: self.schema.clone(),
+            name: name.to_string(),
+        }
+    }
+}
+
+// Identifier parts of a dotted name
+fn split_name(name: &str) -> Option<Vec<String>> {
+    let mut parts = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = name.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                parts.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            '.' if !quoted => parts.push(String::new()),
+            _ => parts.last_mut().unwrap().push(c),
+        }
+    }
+    (!quoted && parts.iter().all(|part| !part.is_empty())).then_some(parts)
+}
+
+// Bounds and allowed values collected from CHECK constraints
+#[derive(Default)]
+struct Bounds {
+    lower: Option<(Value, bool)>,
+    upper: Option<(Value, bool)>,
+    one_of: Option<Vec<Value>>,
+}
+
+impl Bounds {
+    fn admits(&self, value: &Value) -> bool {
+        let above = |(bound, strict): &(Value, bool)| match compare(value, bound) {
+            Ordering::Greater => true,
+            Ordering::Equal => !strict,
+            Ordering::Less => false,
+        };
+        let below = |(bound, strict): &(Value, bool)| match compare(value, bound) {
+            Ordering::Less => true,
+            Ordering::Equal => !strict,
+            Ordering::Greater => false,
+        };
+        self.lower.as_ref().is_none_or(above)
+            && self.upper.as_ref().is_none_or(below)
+            && self.one_of.as_ref().is_none_or(|values| values.contains(value))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lower.is_none() && self.upper.is_none() && self.one_of.is_none()
+    }
+}
+
+// Order of two numbers; other values count as equal
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+        _ => Ordering::Equal,
+    }
+}
+
+type Plan = (Vec<Column>, Vec<ForeignKey>, Vec<UniqueKey>);
+
+fn plan(catalog: &Catalog, table: &Table, rows: u64) -> Result<Plan, String> {
+    let column_rows = catalog.query(
+        "SELECT column_name, data_type, CAST(is_nullable AS VARCHAR) FROM duckdb_columns() \
+         WHERE database_name = $1 AND schema_name = $2 AND table_name = $3 ORDER BY column_index",
+        &[&table.database, &table.schema, &table.name],
+    )?;
+    let mut columns = Vec::with_capacity(column_rows.len());
+    let mut nullable = Vec::with_capacity(column_rows.len());
+    for row in column_rows {
+        let [name, data_type, is_nullable] = <[Option<String>; 3]>::try_from(row).unwrap();
+        let name = name.unwrap_or_default();
+        let data_type = data_type.unwrap_or_default();
+        let kind = Kind::parse(&data_type);
+        columns.push(Column {
+            source: match kind {
+                Some(kind) => default_source(&name, kind),
+                None => Source::Null,
+            },
+            name,
+            data_type,
+            kind,
+        });
+        nullable.push(is_nullable.as_deref() == Some("true"));
+    }
+    let index_of = |name: &str| columns.iter().position(|c| c.name.eq_ignore_ascii_case(name));
+
+    let constraint_rows = catalog.query(
+        "SELECT constraint_type, constraint_text, expression, array_to_string(constraint_column_names, chr(31)), \
+         referenced_table, array_to_string(referenced_column_names, chr(31)) FROM duckdb_constraints() \
+         WHERE database_name = $1 AND schema_name = $2 AND table_name = $3 ORDER BY constraint_index",
+        &[&table.database, &table.schema, &table.name],
+    )?;
+    let mut bounds: Vec<Bounds> = columns.iter().map(|_| Bounds::default()).collect();
+    let mut keys: Vec<Vec<usize>> = Vec::new();
+    let mut references: Vec<(Vec<usize>, String, Vec<String>)> = Vec::new();
+    for row in constraint_rows {
+        let [kind, text, expression, names, referenced_table, referenced_names] =
+            <[Option<String>; 6]>::try_from(row).unwrap();
+        let text = text.unwrap_or_default();
+        let members = names
+            .unwrap_or_default()
+            .split('\u{1f}')
+            .filter(|n| !n.is_empty())
+            .map(|n| index_of(n).ok_or_else(|| format!("unknown column '{}' in {}", n, text)))
+            .collect::<Result<Vec<usize>, String>>()?;
+        match kind.as_deref() {
+            Some("NOT NULL") => members.iter().for_each(|&i| nullable[i] = false),
+            Some("PRIMARY KEY") | Some("UNIQUE") => {
+                members.iter().for_each(|&i| nullable[i] &= kind.as_deref() != Some("PRIMARY KEY"));
+                keys.push(members);
+            }
+            Some("CHECK") => {
+                let expression = expression.unwrap_or_default();
+                let conditions = check::parse(&expression)
+                    .ok_or_else(|| format!("cannot generate values that satisfy {}", text))?;
+                for (name, condition) in conditions {
+                    let i = index_of(&name).ok_or_else(|| format!("unknown column '{}' in {}", name, text))?;
+                    let Some(kind) = columns[i].kind else {
+                        return Err(format!("cannot generate values that satisfy {}", text));
+                    };
+                    add_condition(catalog, &mut bounds[i], kind, condition, &text)?;
+                }
+            }
+            Some("FOREIGN KEY") => {
+                let referenced = referenced_names.unwrap_or_default();
+                references.push((
+                    members,
+                    referenced_table.unwrap_or_default(),
+                    referenced.split('\u{1f}').map(str::to_string).collect(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    for (column, nullable) in columns.iter().zip(&nullable) {
+        if column.kind.is_none() && !nullable {
+            return Err(unsupported(column));
+        }
+    }
+
+    let mut foreign_keys = Vec::new();
+    for (members, referenced_table, referenced_columns) in references {
+        let kinds: Vec<Kind> = members
+            .iter()
+            .map(|&i| columns[i].kind.ok_or_else(|| unsupported(&columns[i])))
+            .collect::<Result<_, _>>()?;
+        let referenced = table.sibling(&referenced_table);
+        let select: Vec<String> = kinds.iter().zip(&referenced_columns).map(|(kind, name)| kind.canonical(&quote(name))).collect();
+        let filter: Vec<String> = referenced_columns.iter().map(|name| format!("{} IS NOT NULL", quote(name))).collect();
+        let sql = format!("SELECT DISTINCT {} FROM {} WHERE {}", select.join(", "), referenced.sql(), filter.join(" AND "));
+        let mut rows = Vec::new();
+        for row in catalog.query(&sql, &[])? {
+            let values: Vec<Value> = row
+                .iter()
+                .zip(&kinds)
+                .map(|(text, kind)| kind.value(text.as_deref().unwrap_or_default()))
+                .collect::<Option<_>>()
+                .ok_or_else(|| format!("cannot read the values of {}", referenced_table))?;
+            if members.iter().zip(&values).all(|(&i, value)| bounds[i].admits(value)) {
+                rows.push(values);
+            }
+        }
+        if rows.is_empty() {
+            if members.iter().any(|&i| !nullable[i]) {
+                return Err(format!("table '{}' has no rows to reference", referenced_table));
+            }
+            members.iter().for_each(|&i| columns[i].source = Source::Null);
+            continue;
+        }
+        members.iter().for_each(|&i| columns[i].source = Source::Reference);
+        foreign_keys.push(ForeignKey { columns: members, rows });
+    }
+
+    // A single integer key that is not a reference counts up
+    let mut sequences = Vec::new();
+    for key in &keys {
+        let &[i] = key.as_slice() else {
+            continue;
+        };
+        let column = &columns[i];
+        let Some(kind) = column.kind.filter(|kind| kind.is_integer()) else {
+            continue;
+        };
+        if matches!(column.source, Source::Reference) || bounds[i].one_of.is_some() {
+            continue;
+        }
+        let (mut first, mut last) = kind.limits().unwrap();
+        if let Some((Value::Int(lower), strict)) = &bounds[i].lower {
+            first = first.max(lower + *strict as i128);
+        }
+        if let Some((Value::Int(upper), strict)) = &bounds[i].upper {
+            last = last.min(upper - *strict as i128);
+        }
+        let max = catalog.query(
+            &format!("SELECT CAST(max({}) AS VARCHAR) FROM {}", quote(&column.name), table.sql()),
+            &[],
+        )?;
+        let start = match max.first().and_then(|row| row[0].as_deref()).and_then(|m| m.parse::<i128>().ok()) {
+            Some(max) => (max + 1).max(first),
+            None => first.max(1),
+        };
+        if rows > 0 && start.saturating_add(rows as i128 - 1) > last {
+            return Err(format!("column '{}' has too few unused values left for {} rows", column.name, rows));
+        }
+        sequences.push(i);
+        columns[i].source = Source::Sequence(start);
+    }
+
+    for (i, column) in columns.iter_mut().enumerate() {
+        if matches!(column.source, Source::Reference | Source::Sequence(_) | Source::Null) {
+            continue;
+        }
+        if let Some(kind) = column.kind {
+            if !bounds[i].is_empty() {
+                column.source = bounded_source(&column.name, kind, std::mem::replace(&mut column.source, Source::Null), &bounds[i])?;
+            }
+        }
+    }
+
+    // Keys with a counting column are unique already
+    let mut unique_keys = Vec::new();
+    for key in keys {
+        if key.iter().any(|i| sequences.contains(i)) {
+            continue;
+        }
+        if let Some(&i) = key.iter().find(|&&i| columns[i].kind.is_none()) {
+            return Err(unsupported(&columns[i]));
+        }
+        let select: Vec<String> = key.iter().map(|&i| columns[i].kind.unwrap().canonical(&quote(&columns[i].name))).collect();
+        let filter: Vec<String> = key.iter().map(|&i| format!("{} IS NOT NULL", quote(&columns[i].name))).collect();
+        let sql = format!("SELECT {} FROM {} WHERE {}", select.join(", "), table.sql(), filter.join(" AND "));
+        let mut existing = Vec::new();
+        for row in catalog.query(&sql, &[])? {
+            let values = row
+                .iter()
+                .zip(&key)
+                .map(|(text, &i)| columns[i].kind.unwrap().value(text.as_deref().unwrap_or_default()))
+                .collect::<Option<Vec<Value>>>()
+                .ok_or_else(|| format!("cannot read the values of {}", table.name))?;
+            existing.push(join_keys(&values.iter().collect::<Vec<_>>()));
+        }
+        unique_keys.push(UniqueKey { columns: key, existing });
+    }
+
+    Ok((columns, foreign_keys, unique_keys))
+}
+
+fn unsupported(column: &Column) -> String {
+    format!("cannot generate values for column '{}' of type {}", column.name, column.data_type)
+}
+
+// Narrow `bounds` by one condition of the CHECK constraint `text`
+fn add_condition(
+    catalog: &Catalog,
+    bounds: &mut Bounds,
+    kind: Kind,
+    condition: Condition,
+    text: &str,
+) -> Result<(), String> {
+    let evaluate = |constant: &str| -> Result<Value, String> {
+        let sql = format!("SELECT {}", kind.canonical(&format!("CAST({} AS {})", constant, kind.sql_name())));
+        let rows = catalog.query(&sql, &[])?;
+        rows.first()
+            .and_then(|row| row[0].as_deref())
+            .and_then(|text| kind.value(text))
+            .ok_or_else(|| format!("cannot generate values that satisfy {}", text))
+    };
+    match condition {
+        // The tighter of two bounds on the same side wins
+        Condition::Lower(constant, strict) => {
+            let value = evaluate(&constant)?;
+            if bounds.lower.as_ref().is_none_or(|(lower, _)| compare(&value, lower) != Ordering::Less) {
+                bounds.lower = Some((value, strict));
+            }
+        }
+        Condition::Upper(constant, strict) => {
+            let value = evaluate(&constant)?;
+            if bounds.upper.as_ref().is_none_or(|(upper, _)| compare(&value, upper) != Ordering::Greater) {
+                bounds.upper = Some((value, strict));
+            }
+        }
+        Condition::OneOf(constants) => {
+            let values = constants.iter().map(|c| evaluate(c)).collect::<Result<Vec<_>, _>>()?;
+            bounds.one_of = Some(match bounds.one_of.take() {
+                Some(previous) => previous.into_iter().filter(|v| values.contains(v)).collect(),
+                None => values,
+            });
+        }
+    }
+    Ok(())
+}
+
+// The source for a column of the given name and kind, before constraints
+fn default_source(name: &str, kind: Kind) -> Source {
+    let words = words(name);
+    let is = |entries: &[&str]| entries.iter().any(|entry| spells(&words, entry, false));
+    let clamp = |low: i128, high: i128| {
+        let (min, max) = kind.limits().unwrap();
+        Source::Int(low.clamp(min, max), high.clamp(min, max))
+    };
+    match kind {
+        Kind::Boolean => Source::Int(0, 1),
+        Kind::Varchar => {
+            let generator = TEXT_COLUMNS
+                .iter()
+                .find(|(entry, anywhere, _)| spells(&words, entry, *anywhere))
+                .map_or("words_word", |(_, _, generator)| generator);
+            Source::Text(registry::lookup(generator).unwrap())
+        }
+        Kind::Uuid => Source::Uuid,
+        Kind::Float | Kind::Double if is(&["lat", "latitude"]) => Source::Float(-90.0, 90.0),
+        Kind::Float | Kind::Double if is(&["lon", "lng", "longitude"]) => Source::Float(-180.0, 180.0),
+        Kind::Float | Kind::Double => Source::Float(0.0, 1000.0),
+        // Up to 1000, or the largest value of the type when that is smaller
+        Kind::Decimal(_, scale) => clamp(0, 10i128.pow(scale as u32).saturating_mul(1000)),
+        Kind::Date if is(&["birthdate", "birthday", "dateofbirth", "dob"]) => Source::Int(BIRTHDATE_MIN, BIRTHDATE_MAX),
+        Kind::Date => Source::Int(DATE_MIN, DATE_MAX),
+        Kind::Timestamp | Kind::TimestampTz => Source::Int(DATE_MIN * MICROS_PER_DAY, (DATE_MAX + 1) * MICROS_PER_DAY - 1),
+        Kind::Time => Source::Int(0, MICROS_PER_DAY - 1),
+        _ if is(&["age"]) => clamp(18, 90),
+        _ if is(&["year"]) => clamp(1950, 2030),
+        _ if is(&["quantity", "qty", "count"]) => clamp(1, 100),
+        _ => clamp(1, 1000),
+    }
+}
+
+// The words of a column name in lower case, split at anything but letters and
+// digits, between letters and digits and at camelCase humps: `billingAddress2`
+// gives billing, address, 2 and `IPAddress` ip, address
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            words.extend((!word.is_empty()).then(|| std::mem::take(&mut word)));
+            continue;
+        }
+        if let Some(&previous) = chars[..i].last().filter(|_| !word.is_empty()) {
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            let boundary = (previous.is_lowercase() && c.is_uppercase())
+                || (previous.is_uppercase() && c.is_uppercase() && next_lower)
+                || previous.is_numeric() != c.is_numeric();
+            if boundary {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    words.extend((!word.is_empty()).then_some(word));
+    words
+}
+
+// Whether a run of whole `words`, at the end unless `anywhere`, spells `entry`
+fn spells(words: &[String], entry: &str, anywhere: bool) -> bool {
+    let ends = if anywhere { 1..=words.len() } else { words.len()..=words.len() };
+    ends.into_iter().any(|end| (0..end).any(|start| words[start..end].concat() == entry))
+}
+
+// `source` moved into `bounds`: a range keeps its width where it can, and a
+// set of allowed values replaces it
+fn bounded_source(name: &str, kind: Kind, source: Source, bounds: &Bounds) -> Result<Source, String> {
+    let no_values = || format!("the CHECK constraints on column '{}' leave no values", name);
+    if let Some(values) = &bounds.one_of {
+        let values: Vec<Value> = values.iter().filter(|v| bounds.admits(v)).cloned().collect();
+        return if values.is_empty() { Err(no_values()) } else { Ok(Source::Choice(values)) };
+    }
+    match source {
+        Source::Int(low, high) => {
+            let (min, max) = kind.limits().unwrap();
+            let lower = match &bounds.lower {
+                Some((Value::Int(v), strict)) => Some(v + *strict as i128),
+                _ => None,
+            };
+            let upper = match &bounds.upper {
+                Some((Value::Int(v), strict)) => Some(v - *strict as i128),
+                _ => None,
+            };
+            let (low, high) = shift(low, high, lower, upper, |a, b| a.saturating_add(b), |a, b| a.saturating_sub(b));
+            let (low, high) = (low.max(min), high.min(max));
+            if low > high {
+                return Err(no_values());
+            }
+            Ok(Source::Int(low, high))
+        }
+        Source::Float(low, high) => {
+            let lower = match &bounds.lower {
+                Some((Value::Float(v), strict)) => Some(if *strict { v.next_up() } else { *v }),
+                _ => None,
+            };
+            let upper = match &bounds.upper {
+                Some((Value::Float(v), strict)) => Some(if *strict { v.next_down() } else { *v }),
+                _ => None,
+            };
+            let (low, high) = shift(low, high, lower, upper, |a, b| a + b, |a, b| a - b);
+            if low > high {
+                return Err(no_values());
+            }
+            Ok(Source::Float(low, high))
+        }
+        _ => Err(format!("cannot generate values for column '{}' within its CHECK constraints", name)),
+    }
+}
+
+// Move the range [low, high] to fit the bounds: when it lies entirely outside
+// a bound it is moved next to it, otherwise it is cut off
+fn shift<T: PartialOrd + Copy>(
+    low: T,
+    high: T,
+    lower: Option<T>,
+    upper: Option<T>,
+    add: impl Fn(T, T) -> T,
+    sub: impl Fn(T, T) -> T,
+) -> (T, T) {
+    let (mut low, mut high) = (low, high);
+    let width = sub(high, low);
+    if let Some(lower) = lower {
+        if high < lower {
+            high = add(lower, width);
+        }
+        if low < lower {
+            low = lower;
+        }
+    }
+    if let Some(upper) = upper {
+        if low > upper {
+            low = sub(upper, width);
+        }
+        if high > upper {
+            high = upper;
+        }
+    }
+    if let Some(lower) = lower {
+        if low < lower {
+            low = lower;
+        }
+    }
+    (low, high)
+}
+
+fn draw(source: &Source, row_number: i128) -> Value {
+    match source {
+        Source::Null | Source::Reference => Value::Null,
+        Source::Sequence(start) => Value::Int(start + row_number),
+        &Source::Int(low, high) => Value::Int(number::int(low, high).unwrap_or(low)),
+        &Source::Float(low, high) => Value::Float(low + (high - low) * rng::unit()),
+        Source::Text(generator) => Value::Text(generator.to_text()),
+        Source::Uuid => Value::Uuid(uuid::v4()),
+        Source::Choice(values) => rng::pick(&values.iter().collect::<Vec<_>>()).clone(),
+    }
+}
+
+fn pick_reference(key: &ForeignKey, row: &mut [Value]) {
+    let referenced = &key.rows[rng::below(key.rows.len() as u64) as usize];
+    for (&i, value) in key.columns.iter().zip(referenced) {
+        row[i] = value.clone();
+    }
+}
+
+fn join_keys(values: &[&Value]) -> String {
+    values.iter().map(|v| v.key()).collect::<Vec<_>>().join("\u{1f}")
+}
+
+// Redraw the columns of `key` in `row` until their values are new
+fn make_unique(
+    bind_data: &FillBindData,
+    key: &UniqueKey,
+    issued: &mut Issued,
+    row: &mut [Value],
+    row_number: i128,
+) -> Result<(), String> {
+    let columns = &bind_data.columns;
+    // A lone text column falls back to suffixes
+    if let [i] = key.columns.as_slice() {
+        if let Source::Text(generator) = columns[*i].source {
+            let mut first = Some(row[*i].key());
+            let value = issued.take(|| first.take().unwrap_or_else(|| generator.to_text()));
+            row[*i] = Value::Text(value);
+            return Ok(());
+        }
+    }
+
+    let mut first = true;
+    let found = issued.take_exact(|| {
+        if !first {
+            for &i in &key.columns {
+                match &columns[i].source {
+                    Source::Reference => {
+                        let foreign_key = bind_data.foreign_keys.iter().find(|k| k.columns.contains(&i)).unwrap();
+                        pick_reference(foreign_key, row);
+                    }
+                    source => row[i] = draw(source, row_number),
+                }
+            }
+        }
+        first = false;
+        join_keys(&key.columns.iter().map(|&i| &row[i]).collect::<Vec<_>>())
+    });
+    if found.is_none() {
+        let names: Vec<&str> = key.columns.iter().map(|&i| columns[i].name.as_str()).collect();
+        return Err(format!("fakeit_fill: ran out of unique values for ({})", names.join(", ")));
+    }
+    Ok(())
+}
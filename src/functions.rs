@@ -0,0 +1,354 @@
+// The function registry and the fakeit_functions() table function.
+//
+// Every function is listed once in FUNCTIONS (lib.rs) with its SQL name and
+// category. Scalar functions declare their overloads and the generator their
+// name stands for in `Overloads`, table functions their parameters in
+// `Parameters`. The entrypoint registers exactly that list, generator names
+// are looked up in it (see registry.rs), and fakeit_functions() reports it, so
+// none of them can disagree.
+//
+//   SELECT name, return_type, sample FROM fakeit_functions() WHERE category = 'name'
+
+use duckdb::core::{DataChunkHandle, Inserter, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vscalar::{ScalarFunctionSignature, VScalar};
+use duckdb::vtab::arrow::WritableVector;
+use duckdb::vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab};
+use duckdb::Connection;
+use libduckdb_sys as ffi;
+use std::error::Error;
+use std::ffi::{c_char, c_void, CString};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::catalog::Catalog;
+use super::registry::Generator;
+use super::rng;
+
+// The SQL types used in function signatures
+#[derive(Clone, Copy)]
+pub enum Type {
+    Any,
+    Bigint,
+    Boolean,
+    Date,
+    Double,
+    Hugeint,
+    Integer,
+    Interval,
+    Timestamp,
+    TimestampTz,
+    UBigint,
+    USmallint,
+    UTinyint,
+    Uuid,
+    Varchar,
+    // What table functions return; it has no logical type
+    Table,
+    Decimal(u8, u8),
+    List(&'static Type),
+    Struct(&'static [(&'static str, Type)]),
+}
+
+impl Type {
+    pub fn handle(self) -> LogicalTypeHandle {
+        let id = match self {
+            Type::Any => LogicalTypeId::Any,
+            Type::Bigint => LogicalTypeId::Bigint,
+            Type::Boolean => LogicalTypeId::Boolean,
+            Type::Date => LogicalTypeId::Date,
+            Type::Double => LogicalTypeId::Double,
+            Type::Hugeint => LogicalTypeId::Hugeint,
+            Type::Integer => LogicalTypeId::Integer,
+            Type::Interval => LogicalTypeId::Interval,
+            Type::Timestamp => LogicalTypeId::Timestamp,
+            Type::TimestampTz => LogicalTypeId::TimestampTZ,
+            Type::UBigint => LogicalTypeId::UBigint,
+            Type::USmallint => LogicalTypeId::USmallint,
+            Type::UTinyint => LogicalTypeId::UTinyint,
+            Type::Uuid => LogicalTypeId::Uuid,
+            Type::Varchar => LogicalTypeId::Varchar,
+            Type::Table => unreachable!("TABLE is not a type of values"),
+            Type::Decimal(width, scale) => return LogicalTypeHandle::decimal(width, scale),
+            Type::List(child) => return LogicalTypeHandle::list(&child.handle()),
+            Type::Struct(fields) => {
+                let fields: Vec<_> = fields.iter().map(|&(name, t)| (name, t.handle())).collect();
+                return LogicalTypeHandle::struct_type(&fields);
+            }
+        };
+        LogicalTypeHandle::from(id)
+    }
+
+    // The type for the C API; the caller destroys it
+    pub unsafe fn raw(self) -> ffi::duckdb_logical_type {
+        match self {
+            Type::Decimal(width, scale) => ffi::duckdb_create_decimal_type(width, scale),
+            Type::List(child) => {
+                let mut child = child.raw();
+                let list = ffi::duckdb_create_list_type(child);
+                ffi::duckdb_destroy_logical_type(&mut child);
+                list
+            }
+            Type::Struct(fields) => {
+                let names: Vec<CString> = fields.iter().map(|&(name, _)| c_string(name)).collect();
+                let mut names: Vec<*const c_char> = names.iter().map(|name| name.as_ptr()).collect();
+                let mut types: Vec<_> = fields.iter().map(|&(_, t)| t.raw()).collect();
+                let raw = ffi::duckdb_create_struct_type(types.as_mut_ptr(), names.as_mut_ptr(), fields.len() as u64);
+                types.iter_mut().for_each(|t| ffi::duckdb_destroy_logical_type(t));
+                raw
+            }
+            _ => ffi::duckdb_create_logical_type(self.handle().raw_id()),
+        }
+    }
+
+    // The type as DuckDB spells it
+    pub fn name(self) -> String {
+        match self {
+            Type::Any => "ANY".to_string(),
+            Type::Bigint => "BIGINT".to_string(),
+            Type::Boolean => "BOOLEAN".to_string(),
+            Type::Date => "DATE".to_string(),
+            Type::Double => "DOUBLE".to_string(),
+            Type::Hugeint => "HUGEINT".to_string(),
+            Type::Integer => "INTEGER".to_string(),
+            Type::Interval => "INTERVAL".to_string(),
+            Type::Timestamp => "TIMESTAMP".to_string(),
+            Type::TimestampTz => "TIMESTAMP WITH TIME ZONE".to_string(),
+            Type::UBigint => "UBIGINT".to_string(),
+            Type::USmallint => "USMALLINT".to_string(),
+            Type::UTinyint => "UTINYINT".to_string(),
+            Type::Uuid => "UUID".to_string(),
+            Type::Varchar => "VARCHAR".to_string(),
+            Type::Table => "TABLE".to_string(),
+            Type::Decimal(width, scale) => format!("DECIMAL({},{})", width, scale),
+            Type::List(child) => format!("{}[]", child.name()),
+            Type::Struct(fields) => {
+                let fields: Vec<_> = fields.iter().map(|&(name, t)| format!("{} {}", name, t.name())).collect();
+                format!("STRUCT({})", fields.join(", "))
+            }
+        }
+    }
+}
+
+// Parameter types and return type of one overload
+pub type Overload = (&'static [Type], Type);
+
+pub trait Overloads {
+    const OVERLOADS: &'static [Overload];
+    // The generator that the name of the function selects in fakeit(),
+    // fakeit_unique() and the other functions taking generator names
+    const GENERATOR: Option<Generator> = None;
+
+    // `raw` is the same database through the C API, for the functions that
+    // duckdb-rs cannot register
+    fn register(con: &Connection, _raw: ffi::duckdb_connection, name: &str) -> duckdb::Result<()>
+    where
+        Self: VScalar<State = ()> + Sized,
+    {
+        con.register_scalar_function::<Self>(name)
+    }
+}
+
+// Parameters of a table function
+pub trait Parameters {
+    const PARAMETERS: &'static [Type] = &[];
+
+    fn named_parameters() -> Vec<(&'static str, Type)> {
+        Vec::new()
+    }
+
+    fn register(con: &Connection, name: &str, _catalog: &Catalog) -> duckdb::Result<()>
+    where
+        Self: VTab + Sized,
+    {
+        con.register_table_function::<Self>(name)
+    }
+}
+
+// Registers a scalar function through the C API, for the functions that need
+// a bind callback, which duckdb-rs does not offer. The callbacks of every
+// overload get `extra_info`, which must live as long as the database.
+pub unsafe fn register_with_bind(
+    con: ffi::duckdb_connection,
+    name: &str,
+    overloads: &[Overload],
+    bind: unsafe extern "C" fn(ffi::duckdb_bind_info),
+    invoke: unsafe extern "C" fn(ffi::duckdb_function_info, ffi::duckdb_data_chunk, ffi::duckdb_vector),
+    extra_info: *const c_void,
+) -> duckdb::Result<()> {
+    let name = c_string(name);
+    let mut set = ffi::duckdb_create_scalar_function_set(name.as_ptr());
+    for (params, ret) in overloads {
+        let mut function = ffi::duckdb_create_scalar_function();
+        ffi::duckdb_scalar_function_set_name(function, name.as_ptr());
+        for param in params.iter() {
+            let mut t = param.raw();
+            ffi::duckdb_scalar_function_add_parameter(function, t);
+            ffi::duckdb_destroy_logical_type(&mut t);
+        }
+        let mut t = ret.raw();
+        ffi::duckdb_scalar_function_set_return_type(function, t);
+        ffi::duckdb_destroy_logical_type(&mut t);
+        ffi::duckdb_scalar_function_set_volatile(function);
+        ffi::duckdb_scalar_function_set_extra_info(function, extra_info as *mut c_void, None);
+        ffi::duckdb_scalar_function_set_bind(function, Some(bind));
+        ffi::duckdb_scalar_function_set_function(function, Some(invoke));
+        let state = ffi::duckdb_add_scalar_function_to_set(set, function);
+        ffi::duckdb_destroy_scalar_function(&mut function);
+        if state != ffi::DuckDBSuccess {
+            ffi::duckdb_destroy_scalar_function_set(&mut set);
+            return Err(duckdb::Error::DuckDBFailure(ffi::Error::new(state), None));
+        }
+    }
+    let state = ffi::duckdb_register_scalar_function_set(con, set);
+    ffi::duckdb_destroy_scalar_function_set(&mut set);
+    if state != ffi::DuckDBSuccess {
+        return Err(duckdb::Error::DuckDBFailure(ffi::Error::new(state), None));
+    }
+    Ok(())
+}
+
+// `text` for the C API, which ends strings at the first NUL; error messages
+// can quote arguments that contain one
+pub fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', "\\0")).unwrap_or_default()
+}
+
+// VScalar::signatures() of a function declaring `Overloads`
+pub fn signatures(overloads: &[Overload]) -> Vec<ScalarFunctionSignature> {
+    overloads
+        .iter()
+        .map(|(params, ret)| ScalarFunctionSignature::exact(params.iter().map(|t| t.handle()).collect(), ret.handle()))
+        .collect()
+}
+
+// VTab::parameters() and VTab::named_parameters() of a table function
+pub fn parameters<T: Parameters>() -> Option<Vec<LogicalTypeHandle>> {
+    Some(T::PARAMETERS.iter().map(|t| t.handle()).collect())
+}
+
+pub fn named_parameters<T: Parameters>() -> Option<Vec<(String, LogicalTypeHandle)>> {
+    Some(T::named_parameters().into_iter().map(|(name, t)| (name.to_string(), t.handle())).collect())
+}
+
+// VScalar::invoke of a scalar function, whose State is always ()
+pub type Invoke = unsafe fn(&(), &mut DataChunkHandle, &mut dyn WritableVector) -> Result<(), Box<dyn Error>>;
+
+pub struct Function {
+    pub name: &'static str,
+    pub category: &'static str,
+    pub overloads: &'static [Overload],
+    pub named_parameters: fn() -> Vec<(&'static str, Type)>,
+    pub generator: Option<Generator>,
+    // None for table functions
+    pub invoke: Option<Invoke>,
+    pub register: fn(&Connection, ffi::duckdb_connection, &Catalog) -> duckdb::Result<()>,
+}
+
+// Must be called while the extension loads, see catalog.rs; `database` is
+// the database that `con` is connected to
+pub unsafe fn register_all(con: &Connection, database: ffi::duckdb_database) -> duckdb::Result<()> {
+    let catalog = Catalog::new(con)?;
+    let mut raw: ffi::duckdb_connection = std::ptr::null_mut();
+    let state = ffi::duckdb_connect(database, &mut raw);
+    if state != ffi::DuckDBSuccess {
+        return Err(duckdb::Error::DuckDBFailure(ffi::Error::new(state), None));
+    }
+    let registered = super::FUNCTIONS.iter().try_for_each(|function| (function.register)(con, raw, &catalog));
+    ffi::duckdb_disconnect(&mut raw);
+    registered
+}
+
+const COLUMNS: &[&str] = &["name", "category", "return_type", "parameters", "sample"];
+
+pub struct FunctionsBindData {
+    // One row per overload
+    rows: Vec<(&'static Function, &'static Overload)>,
+}
+
+pub struct FunctionsInitData {
+    next_row: AtomicUsize,
+}
+
+pub struct FunctionsTable;
+
+impl Parameters for FunctionsTable {}
+
+impl VTab for FunctionsTable {
+    type InitData = FunctionsInitData;
+    type BindData = FunctionsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn Error>> {
+        for name in COLUMNS {
+            let column_type = if *name == "parameters" {
+                LogicalTypeHandle::list(&LogicalTypeHandle::from(LogicalTypeId::Varchar))
+            } else {
+                LogicalTypeHandle::from(LogicalTypeId::Varchar)
+            };
+            bind.add_result_column(name, column_type);
+        }
+        let rows: Vec<_> = super::FUNCTIONS
+            .iter()
+            .flat_map(|function| function.overloads.iter().map(move |overload| (function, overload)))
+            .collect();
+        bind.set_cardinality(rows.len() as u64, true);
+        Ok(FunctionsBindData { rows })
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn Error>> {
+        Ok(FunctionsInitData {
+            next_row: AtomicUsize::new(0),
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn Error>> {
+        let bind_data = func.get_bind_data();
+        let init_data = func.get_init_data();
+
+        let chunk_size = unsafe { ffi::duckdb_vector_size() } as usize;
+        let start = init_data.next_row.fetch_add(chunk_size, Ordering::Relaxed);
+        if start >= bind_data.rows.len() {
+            output.set_len(0);
+            return Ok(());
+        }
+        let rows = &bind_data.rows[start..(start + chunk_size).min(bind_data.rows.len())];
+
+        let name_vec = output.flat_vector(0);
+        let category_vec = output.flat_vector(1);
+        let return_vec = output.flat_vector(2);
+        let mut params_vec = output.list_vector(3);
+        let mut sample_vec = output.flat_vector(4);
+
+        // Positional parameters as types, named ones as `name := TYPE`
+        let params: Vec<Vec<String>> = rows
+            .iter()
+            .map(|&(function, (params, _))| {
+                let named = (function.named_parameters)().into_iter();
+                let named = named.map(|(name, t)| format!("{} := {}", name, t.name()));
+                params.iter().map(|t| t.name()).chain(named).collect()
+            })
+            .collect();
+        let params_child = params_vec.child(params.iter().map(Vec::len).sum());
+
+        let _stream = rng::lock();
+        let mut offset = 0;
+        for (i, (&(function, &(_, ret)), params)) in rows.iter().zip(&params).enumerate() {
+            name_vec.insert(i, function.name);
+            category_vec.insert(i, function.category);
+            return_vec.insert(i, ret.name().as_str());
+            for (j, param) in params.iter().enumerate() {
+                params_child.insert(offset + j, param.as_str());
+            }
+            params_vec.set_entry(i, offset, params.len());
+            offset += params.len();
+
+            // Only zero-argument overloads of named generators can be sampled
+            // without inventing arguments
+            match function.generator {
+                Some(generator) if params.is_empty() => sample_vec.insert(i, generator.to_text().as_str()),
+                _ => sample_vec.set_null(i),
+            }
+        }
+        params_vec.set_len(offset);
+        output.set_len(rows.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+// Hashing of key arguments for the keyed generator overloads.
+//
+// A key is reduced to a stable 64-bit hash that only depends on its value, so
+// the same key yields the same fake value across queries, tables and
+// sessions. Integers of every width hash alike (42::INTEGER and 42::UBIGINT
+// agree), and so do decimals of every width and scale (1.5 and 1.50, 42 and
+// 42.0), which keeps joins across differently typed ID columns consistent.
+
+use duckdb::core::{DataChunkHandle, FlatVector, LogicalTypeId};
+use duckdb::types::DuckString;
+use libduckdb_sys as ffi;
+use std::error::Error;
+
+use super::rng;
+
+// Tags keep values of different kinds apart, e.g. 42 and DATE '1970-02-12'
+const TAG_INTEGER: u8 = b'i';
+const TAG_FLOAT: u8 = b'f';
+const TAG_DECIMAL: u8 = b'n';
+const TAG_STRING: u8 = b's';
+const TAG_DATE: u8 = b'd';
+const TAG_TIME: u8 = b'h';
+const TAG_TIMESTAMP: u8 = b't';
+const TAG_UUID: u8 = b'u';
+const TAG_INTERVAL: u8 = b'v';
+
+// Hash every row of column `col`; NULL keys hash to `None`
+pub unsafe fn hashes(input: &DataChunkHandle, col: usize) -> Result<Vec<Option<u64>>, Box<dyn Error>> {
+    Ok(encodings(input, col)?.into_iter().map(|key| key.map(|bytes| hash(&bytes))).collect())
+}
+
+// The tagged bytes that every row of column `col` hashes from; NULL keys
+// encode to `None`
+pub unsafe fn encodings(input: &DataChunkHandle, col: usize) -> Result<Vec<Option<Vec<u8>>>, Box<dyn Error>> {
+    let len = input.len();
+    let vector = input.flat_vector(col);
+    let logical_type = vector.logical_type();
+
+    let encode_row = |i: usize| -> Result<Vec<u8>, Box<dyn Error>> {
+        let key = match logical_type.id() {
+            LogicalTypeId::Boolean => encode(TAG_INTEGER, &(read::<bool>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::Tinyint => encode(TAG_INTEGER, &(read::<i8>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::Smallint => encode(TAG_INTEGER, &(read::<i16>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::Integer => encode(TAG_INTEGER, &(read::<i32>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::Bigint => encode(TAG_INTEGER, &(read::<i64>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::UTinyint => encode(TAG_INTEGER, &(read::<u8>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::USmallint => encode(TAG_INTEGER, &(read::<u16>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::UInteger => encode(TAG_INTEGER, &(read::<u32>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::UBigint => encode(TAG_INTEGER, &(read::<u64>(&vector, i) as i128).to_le_bytes()),
+            LogicalTypeId::Hugeint => encode(TAG_INTEGER, &read_hugeint(&vector, i).to_le_bytes()),
+            LogicalTypeId::UHugeint => {
+                let value = read::<ffi::duckdb_uhugeint>(&vector, i);
+                // Shares the byte layout of the signed widening for values up to i128::MAX
+                let value = ((value.upper as u128) << 64) | value.lower as u128;
+                encode(TAG_INTEGER, &value.to_le_bytes())
+            }
+            LogicalTypeId::Float => encode(TAG_FLOAT, &(read::<f32>(&vector, i) as f64).to_le_bytes()),
+            LogicalTypeId::Double => encode(TAG_FLOAT, &read::<f64>(&vector, i).to_le_bytes()),
+            LogicalTypeId::Decimal => {
+                let raw = match logical_type.decimal_width() {
+                    0..=4 => read::<i16>(&vector, i) as i128,
+                    5..=9 => read::<i32>(&vector, i) as i128,
+                    10..=18 => read::<i64>(&vector, i) as i128,
+                    _ => read_hugeint(&vector, i),
+                };
+                decimal(raw, logical_type.decimal_scale())
+            }
+            LogicalTypeId::Varchar | LogicalTypeId::Blob => {
+                let mut value = read::<ffi::duckdb_string_t>(&vector, i);
+                encode(TAG_STRING, DuckString::new(&mut value).as_bytes())
+            }
+            LogicalTypeId::Date => encode(TAG_DATE, &read::<i32>(&vector, i).to_le_bytes()),
+            LogicalTypeId::Time => encode(TAG_TIME, &read::<i64>(&vector, i).to_le_bytes()),
+            LogicalTypeId::Timestamp | LogicalTypeId::TimestampTZ => {
+                encode(TAG_TIMESTAMP, &read::<i64>(&vector, i).to_le_bytes())
+            }
+            LogicalTypeId::TimestampS => {
+                encode(TAG_TIMESTAMP, &(read::<i64>(&vector, i) * 1_000_000).to_le_bytes())
+            }
+            LogicalTypeId::TimestampMs => {
+                encode(TAG_TIMESTAMP, &(read::<i64>(&vector, i) * 1_000).to_le_bytes())
+            }
+            LogicalTypeId::TimestampNs => {
+                encode(TAG_TIMESTAMP, &(read::<i64>(&vector, i) / 1_000).to_le_bytes())
+            }
+            LogicalTypeId::Uuid => encode(TAG_UUID, &read_hugeint(&vector, i).to_le_bytes()),
+            LogicalTypeId::Interval => {
+                let value = read::<ffi::duckdb_interval>(&vector, i);
+                let mut bytes = Vec::with_capacity(16);
+                bytes.extend_from_slice(&value.months.to_le_bytes());
+                bytes.extend_from_slice(&value.days.to_le_bytes());
+                bytes.extend_from_slice(&value.micros.to_le_bytes());
+                encode(TAG_INTERVAL, &bytes)
+            }
+            other => return Err(format!("Unsupported key type: {:?}", other).into()),
+        };
+        Ok(key)
+    };
+
+    let mut keys = Vec::with_capacity(len);
+    for i in 0..len {
+        if vector.row_is_null(i as u64) {
+            keys.push(None);
+        } else {
+            keys.push(Some(encode_row(i)?));
+        }
+    }
+    Ok(keys)
+}
+
+unsafe fn read<T: Copy>(vector: &FlatVector, row: usize) -> T {
+    *vector.as_mut_ptr::<T>().add(row)
+}
+
+unsafe fn read_hugeint(vector: &FlatVector, row: usize) -> i128 {
+    let value = read::<ffi::duckdb_hugeint>(vector, row);
+    ((value.upper as i128) << 64) | value.lower as i128
+}
+
+// A decimal of `raw` units of 10^-scale, without the trailing zeros of its
+// fraction so that 1.5 and 1.50 agree; whole numbers encode as integers
+fn decimal(mut raw: i128, mut scale: u8) -> Vec<u8> {
+    while scale > 0 && raw % 10 == 0 {
+        raw /= 10;
+        scale -= 1;
+    }
+    if scale == 0 {
+        return encode(TAG_INTEGER, &raw.to_le_bytes());
+    }
+    let mut bytes = raw.to_le_bytes().to_vec();
+    bytes.push(scale);
+    encode(TAG_DECIMAL, &bytes)
+}
+
+fn encode(tag: u8, bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(1 + bytes.len());
+    encoded.push(tag);
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+// FNV-1a over the tagged bytes, finished with the stream mixer
+fn hash(bytes: &[u8]) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    for &b in bytes {
+        h ^= b as u64;
+        h = h.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    rng::mix(h)
+}
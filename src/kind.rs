@@ -0,0 +1,252 @@
+// Column types that generated values can be written as, shared by the table
+// functions that take their column types from elsewhere: fakeit_fill from a
+// table, fakeit_children from a query.
+//
+// Values travel as text between the catalog connection and these functions.
+// `Kind::canonical` renders a column in SQL as text that `Kind::value` reads
+// back exactly, and `write` stores a value in an output vector.
+
+use duckdb::core::{FlatVector, Inserter, LogicalTypeHandle, LogicalTypeId};
+use libduckdb_sys as ffi;
+
+use super::number;
+use super::uuid;
+
+pub const MICROS_PER_DAY: i128 = 86_400_000_000;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Boolean,
+    Tinyint,
+    Smallint,
+    Integer,
+    Bigint,
+    UTinyint,
+    USmallint,
+    UInteger,
+    UBigint,
+    Hugeint,
+    Float,
+    Double,
+    // Width and scale
+    Decimal(u8, u8),
+    Varchar,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Uuid,
+}
+
+// A generated value. Integers, decimals (in units of the last digit), dates
+// (in days), times and timestamps (in microseconds) and booleans are all Int.
+#[derive(Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Int(i128),
+    Float(f64),
+    Text(String),
+    Uuid(u128),
+}
+
+impl Value {
+    // Text that tells values of one column apart
+    pub fn key(&self) -> String {
+        match self {
+            Value::Null => String::new(),
+            Value::Int(value) => value.to_string(),
+            Value::Float(value) => value.to_bits().to_string(),
+            Value::Text(text) => text.clone(),
+            Value::Uuid(value) => value.to_string(),
+        }
+    }
+}
+
+impl Kind {
+    // A type as the catalog spells it, e.g. INTEGER or DECIMAL(10,2); None for
+    // the types that cannot be generated
+    pub fn parse(data_type: &str) -> Option<Kind> {
+        Some(match data_type {
+            "BOOLEAN" => Kind::Boolean,
+            "TINYINT" => Kind::Tinyint,
+            "SMALLINT" => Kind::Smallint,
+            "INTEGER" => Kind::Integer,
+            "BIGINT" => Kind::Bigint,
+            "UTINYINT" => Kind::UTinyint,
+            "USMALLINT" => Kind::USmallint,
+            "UINTEGER" => Kind::UInteger,
+            "UBIGINT" => Kind::UBigint,
+            "HUGEINT" => Kind::Hugeint,
+            "FLOAT" => Kind::Float,
+            "DOUBLE" => Kind::Double,
+            "VARCHAR" => Kind::Varchar,
+            "DATE" => Kind::Date,
+            "TIME" => Kind::Time,
+            "TIMESTAMP" => Kind::Timestamp,
+            "TIMESTAMP WITH TIME ZONE" => Kind::TimestampTz,
+            "UUID" => Kind::Uuid,
+            _ => {
+                let modifiers = data_type.strip_prefix("DECIMAL(")?.strip_suffix(')')?;
+                let (width, scale) = modifiers.split_once(',')?;
+                Kind::Decimal(width.trim().parse().ok()?, scale.trim().parse().ok()?)
+            }
+        })
+    }
+
+    pub fn logical_type(self) -> LogicalTypeHandle {
+        let id = match self {
+            Kind::Boolean => LogicalTypeId::Boolean,
+            Kind::Tinyint => LogicalTypeId::Tinyint,
+            Kind::Smallint => LogicalTypeId::Smallint,
+            Kind::Integer => LogicalTypeId::Integer,
+            Kind::Bigint => LogicalTypeId::Bigint,
+            Kind::UTinyint => LogicalTypeId::UTinyint,
+            Kind::USmallint => LogicalTypeId::USmallint,
+            Kind::UInteger => LogicalTypeId::UInteger,
+            Kind::UBigint => LogicalTypeId::UBigint,
+            Kind::Hugeint => LogicalTypeId::Hugeint,
+            Kind::Float => LogicalTypeId::Float,
+            Kind::Double => LogicalTypeId::Double,
+            Kind::Decimal(width, scale) => return LogicalTypeHandle::decimal(width, scale),
+            Kind::Varchar => LogicalTypeId::Varchar,
+            Kind::Date => LogicalTypeId::Date,
+            Kind::Time => LogicalTypeId::Time,
+            Kind::Timestamp => LogicalTypeId::Timestamp,
+            Kind::TimestampTz => LogicalTypeId::TimestampTZ,
+            Kind::Uuid => LogicalTypeId::Uuid,
+        };
+        LogicalTypeHandle::from(id)
+    }
+
+    // Smallest and largest Int value of the kind, None for kinds stored otherwise
+    pub fn limits(self) -> Option<(i128, i128)> {
+        Some(match self {
+            Kind::Boolean => (0, 1),
+            Kind::Tinyint => (i8::MIN as i128, i8::MAX as i128),
+            Kind::Smallint => (i16::MIN as i128, i16::MAX as i128),
+            Kind::Integer => (i32::MIN as i128, i32::MAX as i128),
+            Kind::Bigint => (i64::MIN as i128, i64::MAX as i128),
+            Kind::UTinyint => (0, u8::MAX as i128),
+            Kind::USmallint => (0, u16::MAX as i128),
+            Kind::UInteger => (0, u32::MAX as i128),
+            Kind::UBigint => (0, u64::MAX as i128),
+            Kind::Hugeint => (i128::MIN, i128::MAX),
+            Kind::Decimal(width, _) => {
+                let max = 10i128.pow(width as u32) - 1;
+                (-max, max)
+            }
+            Kind::Date => (i32::MIN as i128, i32::MAX as i128),
+            Kind::Time => (0, MICROS_PER_DAY - 1),
+            Kind::Timestamp | Kind::TimestampTz => (i64::MIN as i128, i64::MAX as i128),
+            Kind::Float | Kind::Double | Kind::Varchar | Kind::Uuid => return None,
+        })
+    }
+
+    pub fn is_integer(self) -> bool {
+        matches!(
+            self,
+            Kind::Tinyint
+                | Kind::Smallint
+                | Kind::Integer
+                | Kind::Bigint
+                | Kind::UTinyint
+                | Kind::USmallint
+                | Kind::UInteger
+                | Kind::UBigint
+                | Kind::Hugeint
+        )
+    }
+
+    // SQL that renders `expr` of this kind as the VARCHAR that `value` reads
+    pub fn canonical(self, expr: &str) -> String {
+        match self {
+            Kind::Date => format!("CAST(({}) - DATE '1970-01-01' AS VARCHAR)", expr),
+            Kind::Time => format!("CAST(epoch_us(DATE '1970-01-01' + ({})) AS VARCHAR)", expr),
+            Kind::Timestamp | Kind::TimestampTz => format!("CAST(epoch_us({}) AS VARCHAR)", expr),
+            Kind::Float | Kind::Double => format!("CAST(CAST({} AS DOUBLE) AS VARCHAR)", expr),
+            _ => format!("CAST({} AS VARCHAR)", expr),
+        }
+    }
+
+    pub fn value(self, text: &str) -> Option<Value> {
+        Some(match self {
+            Kind::Boolean => Value::Int((text == "true") as i128),
+            Kind::Decimal(_, scale) => Value::Int(decimal_units(text, scale)?),
+            Kind::Float | Kind::Double => Value::Float(text.parse().ok()?),
+            Kind::Varchar => Value::Text(text.to_string()),
+            Kind::Uuid => Value::Uuid(u128::from_str_radix(&text.replace('-', ""), 16).ok()?),
+            _ => Value::Int(text.parse().ok()?),
+        })
+    }
+
+    // The type as SQL, for casts
+    pub fn sql_name(self) -> String {
+        let name = match self {
+            Kind::Boolean => "BOOLEAN",
+            Kind::Tinyint => "TINYINT",
+            Kind::Smallint => "SMALLINT",
+            Kind::Integer => "INTEGER",
+            Kind::Bigint => "BIGINT",
+            Kind::UTinyint => "UTINYINT",
+            Kind::USmallint => "USMALLINT",
+            Kind::UInteger => "UINTEGER",
+            Kind::UBigint => "UBIGINT",
+            Kind::Hugeint => "HUGEINT",
+            Kind::Float => "FLOAT",
+            Kind::Double => "DOUBLE",
+            Kind::Decimal(width, scale) => return format!("DECIMAL({}, {})", width, scale),
+            Kind::Varchar => "VARCHAR",
+            Kind::Date => "DATE",
+            Kind::Time => "TIME",
+            Kind::Timestamp => "TIMESTAMP",
+            Kind::TimestampTz => "TIMESTAMPTZ",
+            Kind::Uuid => "UUID",
+        };
+        name.to_string()
+    }
+}
+
+// A decimal such as "-12.5" as a count of 10^-scale
+fn decimal_units(text: &str, scale: u8) -> Option<i128> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if fraction.len() > scale as usize {
+        return None;
+    }
+    let units: i128 = format!("{}{:0<width$}", whole, fraction, width = scale as usize).parse().ok()?;
+    Some(if negative { -units } else { units })
+}
+
+// Store `value` in row `i` of `vector`, of the given kind
+pub fn write(vector: &mut FlatVector, kind: Kind, i: usize, value: &Value) {
+    match (kind, value) {
+        (_, Value::Null) => vector.set_null(i),
+        (Kind::Varchar, Value::Text(text)) => vector.insert(i, text.as_str()),
+        (Kind::Uuid, &Value::Uuid(uuid)) => vector.as_mut_slice::<ffi::duckdb_hugeint>()[i] = uuid::to_hugeint(uuid),
+        (Kind::Float, &Value::Float(f)) => vector.as_mut_slice::<f32>()[i] = f as f32,
+        (Kind::Double, &Value::Float(f)) => vector.as_mut_slice::<f64>()[i] = f,
+        (Kind::Boolean, &Value::Int(n)) => vector.as_mut_slice::<bool>()[i] = n != 0,
+        (Kind::Tinyint, &Value::Int(n)) => vector.as_mut_slice::<i8>()[i] = n as i8,
+        (Kind::Smallint, &Value::Int(n)) => vector.as_mut_slice::<i16>()[i] = n as i16,
+        (Kind::Integer | Kind::Date, &Value::Int(n)) => vector.as_mut_slice::<i32>()[i] = n as i32,
+        (Kind::Bigint | Kind::Time | Kind::Timestamp | Kind::TimestampTz, &Value::Int(n)) => {
+            vector.as_mut_slice::<i64>()[i] = n as i64
+        }
+        (Kind::UTinyint, &Value::Int(n)) => vector.as_mut_slice::<u8>()[i] = n as u8,
+        (Kind::USmallint, &Value::Int(n)) => vector.as_mut_slice::<u16>()[i] = n as u16,
+        (Kind::UInteger, &Value::Int(n)) => vector.as_mut_slice::<u32>()[i] = n as u32,
+        (Kind::UBigint, &Value::Int(n)) => vector.as_mut_slice::<u64>()[i] = n as u64,
+        (Kind::Hugeint, &Value::Int(n)) => vector.as_mut_slice::<ffi::duckdb_hugeint>()[i] = number::to_hugeint(n),
+        (Kind::Decimal(width, _), &Value::Int(n)) => match width {
+            0..=4 => vector.as_mut_slice::<i16>()[i] = n as i16,
+            5..=9 => vector.as_mut_slice::<i32>()[i] = n as i32,
+            10..=18 => vector.as_mut_slice::<i64>()[i] = n as i64,
+            _ => vector.as_mut_slice::<ffi::duckdb_hugeint>()[i] = number::to_hugeint(n),
+        },
+        _ => vector.set_null(i),
+    }
+}
+
@@ -1,20 +1,111 @@
 extern crate duckdb;
-extern crate duckdb_loadable_macros;
 extern crate libduckdb_sys;
 
 use duckdb::{Connection, Result};
-use duckdb::core::{DataChunkHandle, Inserter, LogicalTypeHandle, LogicalTypeId};
+use duckdb::core::{DataChunkHandle, FlatVector, Inserter, LogicalTypeId};
 use duckdb::vscalar::{ScalarFunctionSignature, VScalar};
 use duckdb::vtab::arrow::WritableVector;
-use duckdb_loadable_macros::duckdb_entrypoint_c_api;
 use libduckdb_sys as ffi;
 use std::error::Error;
 
-// Macro to create a zero-argument VARCHAR scalar function struct
+use functions::{Overload, Overloads, Type};
+use registry::Generator;
+
+// Modules carry an explicit path because src/wasm_lib.rs includes this file as
+// `mod lib`, which would otherwise resolve them under src/lib/
+#[path = "args.rs"]
+mod args;
+#[path = "catalog.rs"]
+mod catalog;
+#[path = "check.rs"]
+mod check;
+#[path = "children.rs"]
+mod children;
+#[path = "choice.rs"]
+mod choice;
+#[path = "color.rs"]
+mod color;
+#[path = "currency.rs"]
+mod currency;
+#[path = "dispatch.rs"]
+mod dispatch;
+#[path = "distribution.rs"]
+mod distribution;
+#[path = "fill.rs"]
+mod fill;
+#[path = "functions.rs"]
+mod functions;
+#[path = "key.rs"]
+mod key;
+#[path = "kind.rs"]
+mod kind;
+#[path = "locale.rs"]
+mod locale;
+#[path = "mask.rs"]
+mod mask;
+#[path = "number.rs"]
+mod number;
+#[path = "pattern.rs"]
+mod pattern;
+#[path = "persons.rs"]
+mod persons;
+#[path = "pseudonym.rs"]
+mod pseudonym;
+#[path = "regex.rs"]
+mod regex;
+#[path = "registry.rs"]
+mod registry;
+#[path = "rng.rs"]
+mod rng;
+#[path = "strict.rs"]
+mod strict;
+#[path = "table.rs"]
+mod table;
+#[path = "template.rs"]
+mod template;
+#[path = "text.rs"]
+mod text;
+#[path = "timeseries.rs"]
+mod timeseries;
+#[path = "unique.rs"]
+mod unique;
+#[path = "uuid.rs"]
+mod uuid;
+#[path = "value.rs"]
+mod value;
+
+// Macro to create a VARCHAR scalar function struct with a zero-argument overload
+// and a keyed overload that derives the value from a hash of its argument.
+// Locale-aware generators, given with their SQL name, also have a keyed
+// overload that takes the locale as second argument.
 macro_rules! varchar_scalar {
+    ($name:ident, $sql_name:literal, $func:expr) => {
+        varchar_scalar!(@scalar $name, $sql_name, $func, [
+            (&[], Type::Varchar),
+            (&[Type::Any], Type::Varchar),
+            (&[Type::Any, Type::Varchar], Type::Varchar),
+        ]);
+    };
     ($name:ident, $func:expr) => {
+        varchar_scalar!(@scalar $name, "", $func, [
+            (&[], Type::Varchar),
+            (&[Type::Any], Type::Varchar),
+        ]);
+    };
+    (@scalar $name:ident, $sql_name:expr, $func:expr, [$($overload:expr),+ $(,)?]) => {
         struct $name;
 
+        impl $name {
+            fn generate() -> String {
+                $func()
+            }
+        }
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[$($overload),+];
+            const GENERATOR: Option<Generator> = Some(Generator::Varchar(Self::generate));
+        }
+
         impl VScalar for $name {
             type State = ();
 
@@ -24,20 +115,39 @@ macro_rules! varchar_scalar {
                 output: &mut dyn WritableVector,
             ) -> Result<(), Box<dyn Error>> {
                 let len = input.len();
-                let output_vec = output.flat_vector();
+                let _stream = rng::lock();
+                let mut output_vec = output.flat_vector();
 
-                for i in 0..len {
-                    let result: String = $func();
-                    output_vec.insert(i, result.as_str());
+                if input.num_columns() == 0 {
+                    for i in 0..len {
+                        let result = Self::generate();
+                        output_vec.insert(i, result.as_str());
+                    }
+                } else {
+                    // Without a locale argument keyed values use the default one
+                    let locales = match input.num_columns() {
+                        1 => vec![Some(None); len],
+                        _ => args::strings(input, 1)
+                            .into_iter()
+                            .map(|code| code.map(|code| locale::find(&code)).transpose())
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(|e| format!("{}: {}", $sql_name, e))?,
+                    };
+                    for (i, (key, locale)) in key::hashes(input, 0)?.into_iter().zip(locales).enumerate() {
+                        match (key, locale) {
+                            (Some(key), Some(locale)) => {
+                                let result = locale::with(locale, || rng::with_key(key, Self::generate));
+                                output_vec.insert(i, result.as_str());
+                            }
+                            _ => output_vec.set_null(i),
+                        }
+                    }
                 }
                 Ok(())
             }
 
             fn signatures() -> Vec<ScalarFunctionSignature> {
-                vec![ScalarFunctionSignature::exact(
-                    vec![],
-                    LogicalTypeHandle::from(LogicalTypeId::Varchar),
-                )]
+                functions::signatures(Self::OVERLOADS)
             }
 
             fn volatile() -> bool {
@@ -47,11 +157,26 @@ macro_rules! varchar_scalar {
     };
 }
 
-// Macro to create a zero-argument BIGINT scalar function struct
+// Macro to create a BIGINT scalar function struct with a zero-argument overload
+// and a keyed overload that derives the value from a hash of its argument
 macro_rules! bigint_scalar {
     ($name:ident, $func:expr) => {
         struct $name;
 
+        impl $name {
+            fn generate() -> i64 {
+                $func() as i64
+            }
+        }
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[], Type::Bigint),
+                (&[Type::Any], Type::Bigint),
+            ];
+            const GENERATOR: Option<Generator> = Some(Generator::Bigint(Self::generate));
+        }
+
         impl VScalar for $name {
             type State = ();
 
@@ -61,20 +186,27 @@ macro_rules! bigint_scalar {
                 output: &mut dyn WritableVector,
             ) -> Result<(), Box<dyn Error>> {
                 let len = input.len();
+                let _stream = rng::lock();
                 let mut output_vec = output.flat_vector();
-                let output_data = output_vec.as_mut_slice::<i64>();
 
-                for i in 0..len {
-                    output_data[i] = $func() as i64;
+                if input.num_columns() == 0 {
+                    let output_data = output_vec.as_mut_slice::<i64>();
+                    for i in 0..len {
+                        output_data[i] = Self::generate();
+                    }
+                } else {
+                    for (i, key) in key::hashes(input, 0)?.into_iter().enumerate() {
+                        match key {
+                            Some(key) => output_vec.as_mut_slice::<i64>()[i] = rng::with_key(key, Self::generate),
+                            None => output_vec.set_null(i),
+                        }
+                    }
                 }
                 Ok(())
             }
 
             fn signatures() -> Vec<ScalarFunctionSignature> {
-                vec![ScalarFunctionSignature::exact(
-                    vec![],
-                    LogicalTypeHandle::from(LogicalTypeId::Bigint),
-                )]
+                functions::signatures(Self::OVERLOADS)
             }
 
             fn volatile() -> bool {
@@ -84,11 +216,26 @@ macro_rules! bigint_scalar {
     };
 }
 
-// Macro to create a zero-argument DOUBLE scalar function struct
+// Macro to create a DOUBLE scalar function struct with a zero-argument overload
+// and a keyed overload that derives the value from a hash of its argument
 macro_rules! double_scalar {
     ($name:ident, $func:expr) => {
         struct $name;
 
+        impl $name {
+            fn generate() -> f64 {
+                $func()
+            }
+        }
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[], Type::Double),
+                (&[Type::Any], Type::Double),
+            ];
+            const GENERATOR: Option<Generator> = Some(Generator::Double(Self::generate));
+        }
+
         impl VScalar for $name {
             type State = ();
 
@@ -98,20 +245,27 @@ macro_rules! double_scalar {
                 output: &mut dyn WritableVector,
             ) -> Result<(), Box<dyn Error>> {
                 let len = input.len();
+                let _stream = rng::lock();
                 let mut output_vec = output.flat_vector();
-                let output_data = output_vec.as_mut_slice::<f64>();
 
-                for i in 0..len {
-                    output_data[i] = $func();
+                if input.num_columns() == 0 {
+                    let output_data = output_vec.as_mut_slice::<f64>();
+                    for i in 0..len {
+                        output_data[i] = Self::generate();
+                    }
+                } else {
+                    for (i, key) in key::hashes(input, 0)?.into_iter().enumerate() {
+                        match key {
+                            Some(key) => output_vec.as_mut_slice::<f64>()[i] = rng::with_key(key, Self::generate),
+                            None => output_vec.set_null(i),
+                        }
+                    }
                 }
                 Ok(())
             }
 
             fn signatures() -> Vec<ScalarFunctionSignature> {
-                vec![ScalarFunctionSignature::exact(
-                    vec![],
-                    LogicalTypeHandle::from(LogicalTypeId::Double),
-                )]
+                functions::signatures(Self::OVERLOADS)
             }
 
             fn volatile() -> bool {
@@ -121,11 +275,26 @@ macro_rules! double_scalar {
     };
 }
 
-// Macro to create a zero-argument BOOLEAN scalar function struct
+// Macro to create a BOOLEAN scalar function struct with a zero-argument overload
+// and a keyed overload that derives the value from a hash of its argument
 macro_rules! boolean_scalar {
     ($name:ident, $func:expr) => {
         struct $name;
 
+        impl $name {
+            fn generate() -> bool {
+                $func()
+            }
+        }
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[], Type::Boolean),
+                (&[Type::Any], Type::Boolean),
+            ];
+            const GENERATOR: Option<Generator> = Some(Generator::Boolean(Self::generate));
+        }
+
         impl VScalar for $name {
             type State = ();
 
@@ -135,20 +304,27 @@ macro_rules! boolean_scalar {
                 output: &mut dyn WritableVector,
             ) -> Result<(), Box<dyn Error>> {
                 let len = input.len();
+                let _stream = rng::lock();
                 let mut output_vec = output.flat_vector();
-                let output_data = output_vec.as_mut_slice::<bool>();
 
-                for i in 0..len {
-                    output_data[i] = $func();
+                if input.num_columns() == 0 {
+                    let output_data = output_vec.as_mut_slice::<bool>();
+                    for i in 0..len {
+                        output_data[i] = Self::generate();
+                    }
+                } else {
+                    for (i, key) in key::hashes(input, 0)?.into_iter().enumerate() {
+                        match key {
+                            Some(key) => output_vec.as_mut_slice::<bool>()[i] = rng::with_key(key, Self::generate),
+                            None => output_vec.set_null(i),
+                        }
+                    }
                 }
                 Ok(())
             }
 
             fn signatures() -> Vec<ScalarFunctionSignature> {
-                vec![ScalarFunctionSignature::exact(
-                    vec![],
-                    LogicalTypeHandle::from(LogicalTypeId::Boolean),
-                )]
+                functions::signatures(Self::OVERLOADS)
             }
 
             fn volatile() -> bool {
@@ -158,11 +334,88 @@ macro_rules! boolean_scalar {
     };
 }
 
-// Macro to create a two-DOUBLE-argument DOUBLE scalar function struct
-macro_rules! double_double_scalar {
+// Macro to create a UUID scalar function struct with a zero-argument overload
+// and a keyed overload that derives the value from a hash of its argument
+macro_rules! uuid_scalar {
     ($name:ident, $func:expr) => {
         struct $name;
 
+        impl $name {
+            fn generate() -> u128 {
+                $func()
+            }
+        }
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[], Type::Uuid),
+                (&[Type::Any], Type::Uuid),
+            ];
+            const GENERATOR: Option<Generator> = Some(Generator::Uuid(Self::generate));
+        }
+
+        impl VScalar for $name {
+            type State = ();
+
+            unsafe fn invoke(
+                _state: &Self::State,
+                input: &mut DataChunkHandle,
+                output: &mut dyn WritableVector,
+            ) -> Result<(), Box<dyn Error>> {
+                let len = input.len();
+                let _stream = rng::lock();
+                let mut output_vec = output.flat_vector();
+
+                if input.num_columns() == 0 {
+                    let output_data = output_vec.as_mut_slice::<ffi::duckdb_hugeint>();
+                    for i in 0..len {
+                        output_data[i] = uuid::to_hugeint(Self::generate());
+                    }
+                } else {
+                    for (i, key) in key::hashes(input, 0)?.into_iter().enumerate() {
+                        match key {
+                            Some(key) => {
+                                output_vec.as_mut_slice::<ffi::duckdb_hugeint>()[i] =
+                                    uuid::to_hugeint(rng::with_key(key, Self::generate))
+                            }
+                            None => output_vec.set_null(i),
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            fn signatures() -> Vec<ScalarFunctionSignature> {
+                functions::signatures(Self::OVERLOADS)
+            }
+
+            fn volatile() -> bool {
+                true
+            }
+        }
+    };
+}
+
+// Macro to create a STRUCT scalar function struct with a zero-argument overload
+// and a keyed overload; $func returns a tuple whose elements fill the listed
+// fields in order
+macro_rules! struct_scalar {
+    ($name:ident, $func:expr, $tuple:ty, [$(($field:literal, $index:tt, $field_type:expr, $t:ty)),+ $(,)?]) => {
+        struct $name;
+
+        impl $name {
+            fn generate() -> $tuple {
+                $func()
+            }
+        }
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[], Type::Struct(&[$(($field, $field_type)),+])),
+                (&[Type::Any], Type::Struct(&[$(($field, $field_type)),+])),
+            ];
+        }
+
         impl VScalar for $name {
             type State = ();
 
@@ -172,28 +425,206 @@ macro_rules! double_double_scalar {
                 output: &mut dyn WritableVector,
             ) -> Result<(), Box<dyn Error>> {
                 let len = input.len();
-                let input1 = input.flat_vector(0);
-                let input2 = input.flat_vector(1);
-                let input_data1 = input1.as_slice::<f64>();
-                let input_data2 = input2.as_slice::<f64>();
+                let _stream = rng::lock();
+                let mut output_vec = output.struct_vector();
+
+                let values: Vec<Option<$tuple>> = if input.num_columns() == 0 {
+                    (0..len).map(|_| Some(Self::generate())).collect()
+                } else {
+                    key::hashes(input, 0)?
+                        .into_iter()
+                        .map(|key| key.map(|key| rng::with_key(key, Self::generate)))
+                        .collect()
+                };
+
+                $(
+                    let mut child = output_vec.child($index, len);
+                    let child_data = child.as_mut_slice::<$t>();
+                    for (i, value) in values.iter().enumerate() {
+                        if let Some(value) = value {
+                            child_data[i] = value.$index;
+                        }
+                    }
+                )+
+                for (i, value) in values.iter().enumerate() {
+                    if value.is_none() {
+                        output_vec.set_null(i);
+                    }
+                }
+                Ok(())
+            }
+
+            fn signatures() -> Vec<ScalarFunctionSignature> {
+                functions::signatures(Self::OVERLOADS)
+            }
+
+            fn volatile() -> bool {
+                true
+            }
+        }
+    };
+}
+
+// Macro to create a scalar function struct that draws uniformly from the inclusive
+// range between its two arguments; arguments and result share one native type
+// stored as the integer $t (days for DATE, microseconds for the timestamps)
+macro_rules! range_scalar {
+    ($name:ident, $sql_name:expr, $sql_type:expr, $t:ty) => {
+        struct $name;
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[$sql_type, $sql_type], $sql_type),
+            ];
+        }
+
+        impl VScalar for $name {
+            type State = ();
+
+            unsafe fn invoke(
+                _state: &Self::State,
+                input: &mut DataChunkHandle,
+                output: &mut dyn WritableVector,
+            ) -> Result<(), Box<dyn Error>> {
+                let rows = args::rows(args::column::<$t>(input, 0), args::column::<$t>(input, 1));
+                let mut output_vec = output.flat_vector();
+
+                let _stream = rng::lock();
+                for (i, row) in rows.into_iter().enumerate() {
+                    let Some((min, max)) = row else {
+                        output_vec.set_null(i);
+                        continue;
+                    };
+                    if min > max {
+                        return Err(format!("{}: min must not be greater than max", $sql_name).into());
+                    }
+                    output_vec.as_mut_slice::<$t>()[i] = rng::between(min as i64, max as i64) as $t;
+                }
+                Ok(())
+            }
+
+            fn signatures() -> Vec<ScalarFunctionSignature> {
+                functions::signatures(Self::OVERLOADS)
+            }
+
+            fn volatile() -> bool {
+                true
+            }
+        }
+    };
+}
+
+// Macro to create a scalar function struct that samples a distribution. Each
+// parameter is read as its native type; $func returns the sample, or a message
+// about an invalid parameter that becomes the query error.
+macro_rules! distribution_scalar {
+    ($name:ident, $sql_name:expr, ($a_type:expr => $a:ty) -> $sql_type:expr, $t:ty, $func:expr) => {
+        distribution_scalar!(@scalar $name, $sql_name, [$a_type], $sql_type, $t, |input| {
+            args::column::<$a>(input, 0).into_iter().map(|row| row.map(|a| $func(a))).collect()
+        });
+    };
+    ($name:ident, $sql_name:expr, ($a_type:expr => $a:ty, $b_type:expr => $b:ty) -> $sql_type:expr, $t:ty, $func:expr) => {
+        distribution_scalar!(@scalar $name, $sql_name, [$a_type, $b_type], $sql_type, $t, |input| {
+            args::rows(args::column::<$a>(input, 0), args::column::<$b>(input, 1))
+                .into_iter()
+                .map(|row| row.map(|(a, b)| $func(a, b)))
+                .collect()
+        });
+    };
+    (@scalar $name:ident, $sql_name:expr, [$($param_type:expr),+], $sql_type:expr, $t:ty, |$input:ident| $samples:expr) => {
+        struct $name;
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[$($param_type),+], $sql_type),
+            ];
+        }
+
+        impl VScalar for $name {
+            type State = ();
+
+            unsafe fn invoke(
+                _state: &Self::State,
+                $input: &mut DataChunkHandle,
+                output: &mut dyn WritableVector,
+            ) -> Result<(), Box<dyn Error>> {
+                let mut output_vec = output.flat_vector();
+
+                let _stream = rng::lock();
+                let samples: Vec<Option<Result<$t, String>>> = $samples;
+                for (i, sample) in samples.into_iter().enumerate() {
+                    match sample {
+                        Some(Ok(value)) => output_vec.as_mut_slice::<$t>()[i] = value,
+                        Some(Err(message)) => return Err(format!("{}: {}", $sql_name, message).into()),
+                        None => output_vec.set_null(i),
+                    }
+                }
+                Ok(())
+            }
+
+            fn signatures() -> Vec<ScalarFunctionSignature> {
+                functions::signatures(Self::OVERLOADS)
+            }
+
+            fn volatile() -> bool {
+                true
+            }
+        }
+    };
+}
 
+// Macro to create a Faker-style fill-in scalar: $fill replaces the placeholders
+// of the pattern with characters of the alphabet given as second argument, or
+// of $default without one
+macro_rules! fill_scalar {
+    ($name:ident, $sql_name:expr, $default:expr, $fill:expr) => {
+        struct $name;
+
+        impl Overloads for $name {
+            const OVERLOADS: &'static [Overload] = &[
+                (&[Type::Varchar], Type::Varchar),
+                (&[Type::Varchar, Type::Varchar], Type::Varchar),
+            ];
+        }
+
+        impl VScalar for $name {
+            type State = ();
+
+            unsafe fn invoke(
+                _state: &Self::State,
+                input: &mut DataChunkHandle,
+                output: &mut dyn WritableVector,
+            ) -> Result<(), Box<dyn Error>> {
+                let patterns = args::strings(input, 0);
+                let alphabets = if input.num_columns() == 2 {
+                    args::strings(input, 1)
+                } else {
+                    vec![Some($default.to_string()); patterns.len()]
+                };
                 let mut output_vec = output.flat_vector();
-                let output_data = output_vec.as_mut_slice::<f64>();
 
-                for i in 0..len {
-                    output_data[i] = $func(input_data1[i], input_data2[i]);
+                let _stream = rng::lock();
+                let mut chars: Option<(String, Vec<char>)> = None;
+                for (i, row) in args::rows(patterns, alphabets).into_iter().enumerate() {
+                    let Some((pattern, alphabet)) = row else {
+                        output_vec.set_null(i);
+                        continue;
+                    };
+                    if alphabet.is_empty() {
+                        return Err(format!("{}: the alphabet must not be empty", $sql_name).into());
+                    }
+                    if chars.as_ref().is_none_or(|(text, _)| *text != alphabet) {
+                        let split = alphabet.chars().collect();
+                        chars = Some((alphabet, split));
+                    }
+                    let (_, alphabet) = chars.as_ref().unwrap();
+                    output_vec.insert(i, $fill(&pattern, alphabet).as_str());
                 }
                 Ok(())
             }
 
             fn signatures() -> Vec<ScalarFunctionSignature> {
-                vec![ScalarFunctionSignature::exact(
-                    vec![
-                        LogicalTypeHandle::from(LogicalTypeId::Double),
-                        LogicalTypeHandle::from(LogicalTypeId::Double),
-                    ],
-                    LogicalTypeHandle::from(LogicalTypeId::Double),
-                )]
+                functions::signatures(Self::OVERLOADS)
             }
 
             fn volatile() -> bool {
@@ -205,35 +636,35 @@ macro_rules! double_double_scalar {
 
 // Define all the scalar functions using macros
 // Address functions
-varchar_scalar!(AddressStreet, fakeit::address::street);
-varchar_scalar!(AddressCity, fakeit::address::city);
-varchar_scalar!(AddressState, fakeit::address::state);
-varchar_scalar!(AddressZip, fakeit::address::zip);
-varchar_scalar!(AddressCountry, fakeit::address::country);
-varchar_scalar!(AddressStreetNumber, fakeit::address::street_number);
+varchar_scalar!(AddressStreet, "fakeit_address_street", locale::address_street);
+varchar_scalar!(AddressCity, "fakeit_address_city", locale::address_city);
+varchar_scalar!(AddressState, "fakeit_address_state", locale::address_state);
+varchar_scalar!(AddressZip, "fakeit_address_zip", locale::address_zip);
+varchar_scalar!(AddressCountry, "fakeit_address_country", locale::address_country);
+varchar_scalar!(AddressStreetNumber, "fakeit_address_street_number", locale::address_street_number);
 varchar_scalar!(AddressStreetPrefix, fakeit::address::street_prefix);
-varchar_scalar!(AddressStreetName, fakeit::address::street_name);
+varchar_scalar!(AddressStreetName, "fakeit_address_street_name", locale::address_street_name);
 varchar_scalar!(AddressStreetSuffix, fakeit::address::street_suffix);
-varchar_scalar!(AddressStateAbr, fakeit::address::state_abr);
-varchar_scalar!(AddressCountryAbr, fakeit::address::country_abr);
+varchar_scalar!(AddressStateAbr, "fakeit_address_state_abr", locale::address_state_abr);
+varchar_scalar!(AddressCountryAbr, "fakeit_address_country_abr", locale::address_country_abr);
 
 // Name functions
-varchar_scalar!(NameFirst, fakeit::name::first);
-varchar_scalar!(NameLast, fakeit::name::last);
-varchar_scalar!(NameFull, fakeit::name::full);
-varchar_scalar!(NamePrefix, fakeit::name::prefix);
+varchar_scalar!(NameFirst, "fakeit_name_first", locale::name_first);
+varchar_scalar!(NameLast, "fakeit_name_last", locale::name_last);
+varchar_scalar!(NameFull, "fakeit_name_full", locale::name_full);
+varchar_scalar!(NamePrefix, "fakeit_name_prefix", locale::name_prefix);
 varchar_scalar!(NameSuffix, fakeit::name::suffix);
 
 // Company functions
-varchar_scalar!(CompanyCompany, fakeit::company::company);
+varchar_scalar!(CompanyCompany, "fakeit_company_company", locale::company_company);
 varchar_scalar!(CompanyBs, fakeit::company::bs);
-varchar_scalar!(CompanySuffix, fakeit::company::company_suffix);
+varchar_scalar!(CompanySuffix, "fakeit_company_company_suffix", locale::company_suffix);
 varchar_scalar!(CompanyBuzzword, fakeit::company::buzzword);
 
 // Contact functions
-varchar_scalar!(ContactEmail, fakeit::contact::email);
-varchar_scalar!(ContactPhone, fakeit::contact::phone);
-varchar_scalar!(ContactPhoneFormatted, fakeit::contact::phone_formatted);
+varchar_scalar!(ContactEmail, "fakeit_contact_email", locale::contact_email);
+varchar_scalar!(ContactPhone, "fakeit_contact_phone", locale::contact_phone);
+varchar_scalar!(ContactPhoneFormatted, "fakeit_contact_phone_formatted", locale::contact_phone_formatted);
 
 // Internet functions
 varchar_scalar!(InternetIpv4Address, fakeit::internet::ipv4_address);
@@ -245,8 +676,98 @@ varchar_scalar!(InternetHttpMethod, fakeit::internet::http_method);
 varchar_scalar!(InternetMacAddress, fakeit::internet::mac_address);
 
 // UUID functions
-varchar_scalar!(UuidV1, fakeit::unique::uuid_v1);
-varchar_scalar!(UuidV4, fakeit::unique::uuid_v4);
+uuid_scalar!(UuidV1, uuid::v1);
+uuid_scalar!(UuidV4, uuid::v4);
+
+// Time-ordered UUID v7, for the current time or for a given timestamp
+struct UuidV7;
+
+impl Overloads for UuidV7 {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[], Type::Uuid),
+        (&[Type::TimestampTz], Type::Uuid),
+    ];
+    const GENERATOR: Option<Generator> = Some(Generator::Uuid(uuid::v7_now));
+}
+
+impl VScalar for UuidV7 {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let len = input.len();
+        let mut output_vec = output.flat_vector();
+
+        let _stream = rng::lock();
+        if input.num_columns() == 0 {
+            let output_data = output_vec.as_mut_slice::<ffi::duckdb_hugeint>();
+            for value in output_data.iter_mut().take(len) {
+                *value = uuid::to_hugeint(uuid::v7_now());
+            }
+            return Ok(());
+        }
+
+        for (i, micros) in args::column::<i64>(input, 0).into_iter().enumerate() {
+            let Some(micros) = micros else {
+                output_vec.set_null(i);
+                continue;
+            };
+            if micros < 0 {
+                return Err("fakeit_uuid_v7: the timestamp must not be before 1970-01-01".into());
+            }
+            output_vec.as_mut_slice::<ffi::duckdb_hugeint>()[i] = uuid::to_hugeint(uuid::v7(micros as u64 / 1000));
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Name-based UUID v5; the same namespace and name always give the same UUID
+struct UuidV5;
+
+impl Overloads for UuidV5 {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Uuid, Type::Varchar], Type::Uuid),
+    ];
+}
+
+impl VScalar for UuidV5 {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let rows = args::rows(args::column::<ffi::duckdb_hugeint>(input, 0), args::strings(input, 1));
+        let mut output_vec = output.flat_vector();
+
+        for (i, row) in rows.into_iter().enumerate() {
+            match row {
+                Some((namespace, name)) => {
+                    let uuid = uuid::v5(uuid::from_hugeint(namespace), name.as_bytes());
+                    output_vec.as_mut_slice::<ffi::duckdb_hugeint>()[i] = uuid::to_hugeint(uuid);
+                }
+                None => output_vec.set_null(i),
+            }
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+}
 
 // Animal functions
 varchar_scalar!(AnimalPetName, fakeit::animal::pet_name);
@@ -269,15 +790,188 @@ varchar_scalar!(BeerBlg, fakeit::beer::blg);
 varchar_scalar!(ColorFull, fakeit::color::full);
 varchar_scalar!(ColorHex, fakeit::color::hex);
 varchar_scalar!(ColorSafe, fakeit::color::safe);
-varchar_scalar!(ColorRgb, || {
-    let rgb = fakeit::color::rgb();
-    format!("[{}, {}, {}]", rgb[0], rgb[1], rgb[2])
-});
+struct_scalar!(ColorRgb, color::rgb, (u8, u8, u8), [
+    ("r", 0, Type::UTinyint, u8),
+    ("g", 1, Type::UTinyint, u8),
+    ("b", 2, Type::UTinyint, u8),
+]);
+struct_scalar!(ColorHsl, color::hsl, (u16, u8, u8), [
+    ("h", 0, Type::USmallint, u16),
+    ("s", 1, Type::UTinyint, u8),
+    ("l", 2, Type::UTinyint, u8),
+]);
+struct_scalar!(ColorCmyk, color::cmyk, (u8, u8, u8, u8), [
+    ("c", 0, Type::UTinyint, u8),
+    ("m", 1, Type::UTinyint, u8),
+    ("y", 2, Type::UTinyint, u8),
+    ("k", 3, Type::UTinyint, u8),
+]);
+
+// Palette function: a list of distinct hex colors that are readable on white
+struct ColorPalette;
+
+impl Overloads for ColorPalette {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Bigint], Type::List(&Type::Varchar)),
+    ];
+}
+
+impl VScalar for ColorPalette {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let counts = args::column::<i64>(input, 0);
+
+        let mut palettes = Vec::with_capacity(counts.len());
+        let _stream = rng::lock();
+        for n in counts {
+            let Some(n) = n else {
+                palettes.push(None);
+                continue;
+            };
+            if !(0..=color::PALETTE_MAX).contains(&n) {
+                return Err(format!(
+                    "fakeit_color_palette: the number of colors must be between 0 and {}, got {}",
+                    color::PALETTE_MAX,
+                    n
+                )
+                .into());
+            }
+            palettes.push(Some(color::palette(n as usize)));
+        }
+
+        let mut list_vec = output.list_vector();
+        let total = palettes.iter().flatten().map(Vec::len).sum();
+        let child = list_vec.child(total);
+        let mut offset = 0;
+        for (i, palette) in palettes.iter().enumerate() {
+            match palette {
+                Some(colors) => {
+                    for (j, hex) in colors.iter().enumerate() {
+                        child.insert(offset + j, hex.as_str());
+                    }
+                    list_vec.set_entry(i, offset, colors.len());
+                    offset += colors.len();
+                }
+                None => list_vec.set_null(i),
+            }
+        }
+        list_vec.set_len(total);
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
 
 // Currency functions
 varchar_scalar!(CurrencyShort, fakeit::currency::short);
 varchar_scalar!(CurrencyLong, fakeit::currency::long);
-varchar_scalar!(CurrencyPrice, || format!("{:.2}", fakeit::currency::price(0.0, 1000.0)));
+
+// Price function: DECIMAL(18,2) amounts between 0 and 1000 or within a range,
+// and DECIMAL(18,3) amounts rounded to the minor units of a given currency
+const CENTS: Type = Type::Decimal(18, 2);
+const MILLS: Type = Type::Decimal(18, 3);
+
+struct CurrencyPrice;
+
+impl CurrencyPrice {
+    fn generate() -> i64 {
+        rng::between(0, 100_000)
+    }
+}
+
+impl Overloads for CurrencyPrice {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[], CENTS),
+        (&[Type::Any], CENTS),
+        (&[CENTS, CENTS], CENTS),
+        (&[MILLS, MILLS, Type::Varchar], MILLS),
+    ];
+    const GENERATOR: Option<Generator> = Some(Generator::Cents(Self::generate));
+}
+
+impl VScalar for CurrencyPrice {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let len = input.len();
+        let _stream = rng::lock();
+        let mut output_vec = output.flat_vector();
+
+        match input.num_columns() {
+            0 => {
+                let output_data = output_vec.as_mut_slice::<i64>();
+                for value in output_data.iter_mut().take(len) {
+                    *value = Self::generate();
+                }
+            }
+            1 => {
+                for (i, key) in key::hashes(input, 0)?.into_iter().enumerate() {
+                    match key {
+                        Some(key) => output_vec.as_mut_slice::<i64>()[i] = rng::with_key(key, Self::generate),
+                        None => output_vec.set_null(i),
+                    }
+                }
+            }
+            columns => {
+                let bounds = args::rows(args::column::<i64>(input, 0), args::column::<i64>(input, 1));
+                // Without a currency argument every row is priced in hundredths
+                let currencies: Vec<Option<Option<String>>> = if columns == 3 {
+                    args::strings(input, 2).into_iter().map(|code| code.map(Some)).collect()
+                } else {
+                    vec![Some(None); len]
+                };
+
+                for (i, row) in args::rows(bounds, currencies).into_iter().enumerate() {
+                    let Some(((min, max), code)) = row else {
+                        output_vec.set_null(i);
+                        continue;
+                    };
+                    if min > max {
+                        return Err("fakeit_currency_price: min must not be greater than max".into());
+                    }
+                    let price = match code {
+                        None => currency::price(min, max, 2, 2),
+                        Some(code) => {
+                            let decimals = currency::minor_units(&code)
+                                .ok_or_else(|| format!("fakeit_currency_price: unknown currency '{}'", code))?;
+                            currency::price(min, max, 3, decimals)
+                        }
+                    };
+                    match price {
+                        Some(price) => output_vec.as_mut_slice::<i64>()[i] = price,
+                        None => {
+                            return Err("fakeit_currency_price: no amount in whole minor units lies between min and max".into())
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
 
 // DateTime functions
 varchar_scalar!(DateTimeMonth, fakeit::datetime::month);
@@ -293,6 +987,9 @@ varchar_scalar!(DateTimeSecond, || fakeit::datetime::second().to_string());
 varchar_scalar!(DateTimeNanosecond, || fakeit::datetime::nanosecond().to_string());
 varchar_scalar!(DateTimeTimezoneOffset, || fakeit::datetime::timezone_offset().to_string());
 varchar_scalar!(DateTimeDate, || format!("{:?}", fakeit::datetime::date()));
+range_scalar!(Date, "fakeit_date", Type::Date, i32);
+range_scalar!(Timestamp, "fakeit_timestamp", Type::Timestamp, i64);
+range_scalar!(TimestampTz, "fakeit_timestamptz", Type::TimestampTz, i64);
 
 // File functions
 varchar_scalar!(FileExtension, fakeit::file::extension);
@@ -340,7 +1037,7 @@ varchar_scalar!(PaymentCreditCardCvv, fakeit::payment::credit_card_cvv);
 varchar_scalar!(PaymentCreditCardLuhnNumber, fakeit::payment::credit_card_luhn_number);
 
 // Person functions
-varchar_scalar!(PersonSsn, fakeit::person::ssn);
+varchar_scalar!(PersonSsn, "fakeit_person_ssn", locale::person_ssn);
 varchar_scalar!(PersonGender, fakeit::person::gender);
 
 // User Agent functions
@@ -367,8 +1064,667 @@ varchar_scalar!(WordsParagraph, || fakeit::words::paragraph(3, 5, 10, String::fr
 varchar_scalar!(WordsQuestion, fakeit::words::question);
 varchar_scalar!(WordsQuote, fakeit::words::quote);
 
-// Generator function
-varchar_scalar!(GeneratorGenerate, || fakeit::generator::generate("{firstname} {lastname}".to_string()));
+// Generator function: renders a template per row. Without a template it
+// produces a full name; with a second argument the values follow that key.
+struct GeneratorGenerate;
+
+impl GeneratorGenerate {
+    fn generate() -> String {
+        format!("{} {}", NameFirst::generate(), NameLast::generate())
+    }
+}
+
+impl Overloads for GeneratorGenerate {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[], Type::Varchar),
+        (&[Type::Varchar], Type::Varchar),
+        (&[Type::Varchar, Type::Any], Type::Varchar),
+    ];
+    const GENERATOR: Option<Generator> = Some(Generator::Varchar(Self::generate));
+}
+
+impl VScalar for GeneratorGenerate {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let len = input.len();
+        let _stream = rng::lock();
+        let mut output_vec = output.flat_vector();
+
+        if input.num_columns() == 0 {
+            for i in 0..len {
+                output_vec.insert(i, Self::generate().as_str());
+            }
+            return Ok(());
+        }
+
+        let sources = args::strings(input, 0);
+        let keys = if input.num_columns() == 2 {
+            Some(key::hashes(input, 1)?)
+        } else {
+            None
+        };
+
+        // A constant template arrives as the same string on every row, so the
+        // last parsed template is kept and only re-parsed when the text changes
+        let mut cached: Option<template::Template> = None;
+        for (i, source) in sources.into_iter().enumerate() {
+            let key = keys.as_ref().map(|keys| keys[i]);
+            let Some(source) = source.filter(|_| key != Some(None)) else {
+                output_vec.set_null(i);
+                continue;
+            };
+            if cached.as_ref().is_none_or(|t| t.source() != source) {
+                let parsed = template::Template::parse(&source)
+                    .map_err(|e| format!("fakeit_generator_generate: {}", e))?;
+                cached = Some(parsed);
+            }
+            let template = cached.as_ref().unwrap();
+            let result = match key.flatten() {
+                Some(key) => rng::with_key(key, || template.render()),
+                None => template.render(),
+            };
+            output_vec.insert(i, result.as_str());
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Nullable wrapper: passes its value through, or NULL with the given probability.
+// DuckDB needs a fixed return type per overload, so there is one per common type.
+struct Nullable;
+
+impl Nullable {
+    // Copy the rows of a fixed-width column that are not dropped
+    unsafe fn pass_through<T: Copy>(input: &DataChunkHandle, output: &mut FlatVector, keep: &[bool]) {
+        for (i, value) in args::column::<T>(input, 0).into_iter().enumerate() {
+            match value {
+                Some(value) if keep[i] => output.as_mut_slice::<T>()[i] = value,
+                _ => output.set_null(i),
+            }
+        }
+    }
+}
+
+impl Overloads for Nullable {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Varchar, Type::Double], Type::Varchar),
+        (&[Type::Boolean, Type::Double], Type::Boolean),
+        (&[Type::Integer, Type::Double], Type::Integer),
+        (&[Type::Bigint, Type::Double], Type::Bigint),
+        (&[Type::Double, Type::Double], Type::Double),
+        // One DECIMAL overload, for prices; see strict.rs for the others
+        (&[CENTS, Type::Double], CENTS),
+        (&[Type::Date, Type::Double], Type::Date),
+        (&[Type::Timestamp, Type::Double], Type::Timestamp),
+        (&[Type::TimestampTz, Type::Double], Type::TimestampTz),
+        (&[Type::Uuid, Type::Double], Type::Uuid),
+    ];
+
+    fn register(_con: &Connection, raw: ffi::duckdb_connection, name: &str) -> duckdb::Result<()> {
+        unsafe { strict::register(raw, name) }
+    }
+}
+
+impl VScalar for Nullable {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        // A row is kept unless its probability is NULL or the draw says drop
+        let mut keep = Vec::with_capacity(input.len());
+        let _stream = rng::lock();
+        for probability in args::column::<f64>(input, 1) {
+            match probability {
+                Some(p) if (0.0..=1.0).contains(&p) => keep.push(rng::unit() >= p),
+                Some(p) => return Err(format!("fakeit_nullable: probability must be between 0 and 1, got {}", p).into()),
+                None => keep.push(false),
+            }
+        }
+
+        let mut output_vec = output.flat_vector();
+        match input.flat_vector(0).logical_type().id() {
+            LogicalTypeId::Varchar => {
+                for (i, value) in args::strings(input, 0).into_iter().enumerate() {
+                    match value {
+                        Some(value) if keep[i] => output_vec.insert(i, value.as_str()),
+                        _ => output_vec.set_null(i),
+                    }
+                }
+            }
+            LogicalTypeId::Boolean => Self::pass_through::<bool>(input, &mut output_vec, &keep),
+            LogicalTypeId::Integer | LogicalTypeId::Date => Self::pass_through::<i32>(input, &mut output_vec, &keep),
+            LogicalTypeId::Uuid => Self::pass_through::<ffi::duckdb_hugeint>(input, &mut output_vec, &keep),
+            // BIGINT, DOUBLE, DECIMAL(18,2) and the timestamps are all 8 bytes wide
+            _ => Self::pass_through::<i64>(input, &mut output_vec, &keep),
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Uniform choice of one element of a list. Like fakeit_nullable it has one
+// overload per element type, and one DECIMAL overload checked by strict.rs;
+// an empty list gives NULL.
+struct Choice;
+
+impl Overloads for Choice {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::List(&Type::Varchar)], Type::Varchar),
+        (&[Type::List(&Type::Boolean)], Type::Boolean),
+        (&[Type::List(&Type::Integer)], Type::Integer),
+        (&[Type::List(&Type::Bigint)], Type::Bigint),
+        (&[Type::List(&Type::Double)], Type::Double),
+        (&[Type::List(&CENTS)], CENTS),
+        (&[Type::List(&Type::Date)], Type::Date),
+        (&[Type::List(&Type::Timestamp)], Type::Timestamp),
+        (&[Type::List(&Type::TimestampTz)], Type::TimestampTz),
+        (&[Type::List(&Type::Uuid)], Type::Uuid),
+    ];
+
+    fn register(_con: &Connection, raw: ffi::duckdb_connection, name: &str) -> duckdb::Result<()> {
+        unsafe { strict::register(raw, name) }
+    }
+}
+
+impl VScalar for Choice {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let _stream = rng::lock();
+        let picks: Vec<Option<usize>> = args::lists(input, 0)
+            .into_iter()
+            .map(|list| {
+                let (offset, len) = list.filter(|&(_, len)| len > 0)?;
+                Some(offset + rng::below(len as u64) as usize)
+            })
+            .collect();
+        choice::write_picks(&args::elements(input, 0), &picks, &mut output.flat_vector());
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+const WEIGHTS: Type = Type::List(&Type::Double);
+
+// Choice of one element of a list, with the matching element of the weights
+// list as its relative probability
+struct WeightedChoice;
+
+impl Overloads for WeightedChoice {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::List(&Type::Varchar), WEIGHTS], Type::Varchar),
+        (&[Type::List(&Type::Boolean), WEIGHTS], Type::Boolean),
+        (&[Type::List(&Type::Integer), WEIGHTS], Type::Integer),
+        (&[Type::List(&Type::Bigint), WEIGHTS], Type::Bigint),
+        (&[Type::List(&Type::Double), WEIGHTS], Type::Double),
+        (&[Type::List(&CENTS), WEIGHTS], CENTS),
+        (&[Type::List(&Type::Date), WEIGHTS], Type::Date),
+        (&[Type::List(&Type::Timestamp), WEIGHTS], Type::Timestamp),
+        (&[Type::List(&Type::TimestampTz), WEIGHTS], Type::TimestampTz),
+        (&[Type::List(&Type::Uuid), WEIGHTS], Type::Uuid),
+    ];
+
+    fn register(_con: &Connection, raw: ffi::duckdb_connection, name: &str) -> duckdb::Result<()> {
+        unsafe { strict::register(raw, name) }
+    }
+}
+
+impl VScalar for WeightedChoice {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let rows = args::rows(args::lists(input, 0), args::lists(input, 1));
+        let weight_elements = args::elements(input, 1);
+        let weights = weight_elements.as_slice::<f64>();
+
+        let _stream = rng::lock();
+        let mut cached: Option<((usize, usize), choice::AliasTable)> = None;
+        let mut picks = Vec::with_capacity(rows.len());
+        for row in rows {
+            let Some(((offset, len), (weights_offset, weights_len))) = row else {
+                picks.push(None);
+                continue;
+            };
+            if len != weights_len {
+                return Err(format!(
+                    "fakeit_weighted_choice: values and weights must have the same length, got {} and {}",
+                    len, weights_len
+                )
+                .into());
+            }
+            if len == 0 {
+                picks.push(None);
+                continue;
+            }
+            let entry = (weights_offset, weights_len);
+            if cached.as_ref().is_none_or(|(cached_entry, _)| *cached_entry != entry) {
+                let range = weights_offset..weights_offset + weights_len;
+                if range.clone().any(|j| weight_elements.row_is_null(j as u64)) {
+                    return Err("fakeit_weighted_choice: weights must not contain NULL".into());
+                }
+                let table = choice::AliasTable::new(&weights[range])
+                    .map_err(|e| format!("fakeit_weighted_choice: {}", e))?;
+                cached = Some((entry, table));
+            }
+            let (_, table) = cached.as_ref().unwrap();
+            picks.push(Some(offset + table.sample()));
+        }
+        choice::write_picks(&args::elements(input, 0), &picks, &mut output.flat_vector());
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Uniform integer between two bounds, in the type of the bounds
+struct Int;
+
+impl Int {
+    unsafe fn draw<T: Copy>(
+        input: &DataChunkHandle,
+        output: &mut FlatVector,
+        to_i128: fn(T) -> i128,
+        from_i128: fn(i128) -> T,
+    ) -> Result<(), Box<dyn Error>> {
+        let rows = args::rows(args::column::<T>(input, 0), args::column::<T>(input, 1));
+        for (i, row) in rows.into_iter().enumerate() {
+            match row {
+                Some((min, max)) => {
+                    let value = number::int(to_i128(min), to_i128(max)).map_err(|e| format!("fakeit_int: {}", e))?;
+                    output.as_mut_slice::<T>()[i] = from_i128(value);
+                }
+                None => output.set_null(i),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Overloads for Int {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Integer, Type::Integer], Type::Integer),
+        (&[Type::Bigint, Type::Bigint], Type::Bigint),
+        (&[Type::UBigint, Type::UBigint], Type::UBigint),
+        (&[Type::Hugeint, Type::Hugeint], Type::Hugeint),
+    ];
+}
+
+impl VScalar for Int {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut output_vec = output.flat_vector();
+        let _stream = rng::lock();
+        match input.flat_vector(0).logical_type().id() {
+            LogicalTypeId::Integer => Self::draw::<i32>(input, &mut output_vec, |v| v as i128, |v| v as i32),
+            LogicalTypeId::Bigint => Self::draw::<i64>(input, &mut output_vec, |v| v as i128, |v| v as i64),
+            LogicalTypeId::UBigint => Self::draw::<u64>(input, &mut output_vec, |v| v as i128, |v| v as u64),
+            _ => Self::draw::<ffi::duckdb_hugeint>(input, &mut output_vec, number::from_hugeint, number::to_hugeint),
+        }
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+const DECIMAL: Type = Type::Decimal(38, number::DECIMAL_SCALE as u8);
+
+// Uniform decimal between two bounds with `scale` decimals
+struct Decimal;
+
+impl Overloads for Decimal {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[DECIMAL, DECIMAL, Type::Integer], DECIMAL),
+    ];
+}
+
+impl VScalar for Decimal {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let bounds = args::rows(
+            args::column::<ffi::duckdb_hugeint>(input, 0),
+            args::column::<ffi::duckdb_hugeint>(input, 1),
+        );
+        let rows = args::rows(bounds, args::column::<i32>(input, 2));
+        let mut output_vec = output.flat_vector();
+
+        let _stream = rng::lock();
+        for (i, row) in rows.into_iter().enumerate() {
+            let Some(((min, max), scale)) = row else {
+                output_vec.set_null(i);
+                continue;
+            };
+            let value = number::decimal(number::from_hugeint(min), number::from_hugeint(max), scale as i64)
+                .map_err(|e| format!("fakeit_decimal: {}", e))?;
+            output_vec.as_mut_slice::<ffi::duckdb_hugeint>()[i] = number::to_hugeint(value);
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// String of n random digits, for account numbers and other fixed-width codes
+struct Digits;
+
+impl Overloads for Digits {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Integer], Type::Varchar),
+    ];
+}
+
+impl VScalar for Digits {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut output_vec = output.flat_vector();
+        let _stream = rng::lock();
+        for (i, n) in args::column::<i32>(input, 0).into_iter().enumerate() {
+            match n {
+                Some(n) => {
+                    let digits = number::digits(n as i64).map_err(|e| format!("fakeit_digits: {}", e))?;
+                    output_vec.insert(i, digits.as_str());
+                }
+                None => output_vec.set_null(i),
+            }
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Strings matching a regular expression. Like templates, a constant pattern
+// arrives as the same string on every row and is compiled only once.
+struct Regex;
+
+impl Overloads for Regex {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Varchar], Type::Varchar),
+    ];
+}
+
+impl VScalar for Regex {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut output_vec = output.flat_vector();
+        let _stream = rng::lock();
+        let mut cached: Option<regex::Pattern> = None;
+        for (i, source) in args::strings(input, 0).into_iter().enumerate() {
+            let Some(source) = source else {
+                output_vec.set_null(i);
+                continue;
+            };
+            if cached.as_ref().is_none_or(|p| p.source() != source) {
+                cached = Some(regex::Pattern::parse(&source).map_err(|e| format!("fakeit_regex: {}", e))?);
+            }
+            output_vec.insert(i, cached.as_ref().unwrap().generate().as_str());
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Mask-like function: replaces the letters and digits of a value with random
+// ones of the same class; with a secret, the replacement follows from an HMAC
+// of the value, so equal values are masked alike
+struct MaskLike;
+
+impl Overloads for MaskLike {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Varchar], Type::Varchar),
+        (&[Type::Varchar, Type::Varchar], Type::Varchar),
+    ];
+}
+
+impl VScalar for MaskLike {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut output_vec = output.flat_vector();
+        let secrets = (input.num_columns() > 1).then(|| args::strings(input, 1));
+
+        let _stream = rng::lock();
+        for (i, value) in args::strings(input, 0).into_iter().enumerate() {
+            let masked = match (value, secrets.as_ref().map(|secrets| &secrets[i])) {
+                (Some(value), None) => mask::mask(&value),
+                (Some(value), Some(Some(secret))) => {
+                    let seed = pseudonym::keyed_seed(secret, "mask_like", value.as_bytes())
+                        .map_err(|e| format!("fakeit_mask_like: {}", e))?;
+                    rng::with_key(seed, || mask::mask(&value))
+                }
+                _ => {
+                    output_vec.set_null(i);
+                    continue;
+                }
+            };
+            output_vec.insert(i, masked.as_str());
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Unique function: a value of the named generator that the same call has not
+// returned before in the statement, as text; see unique.rs for what happens
+// when the generator runs dry
+struct Unique;
+
+impl Overloads for Unique {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Varchar], Type::Varchar),
+    ];
+
+    fn register(_con: &Connection, raw: ffi::duckdb_connection, name: &str) -> duckdb::Result<()> {
+        unsafe { unique::register(raw, name, Self::OVERLOADS) }
+    }
+}
+
+// Only for fakeit(), which gets no state to remember issued values in
+impl VScalar for Unique {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        _input: &mut DataChunkHandle,
+        _output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        Err("fakeit_unique: only unique within a call of its own; call fakeit_unique directly".into())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Dispatch function: calls the function named by the first argument with the
+// others, so that the generator can come from data, e.g. a column of a config
+// table. See dispatch.rs.
+struct Dispatch;
+
+impl Overloads for Dispatch {
+    // As many arguments as the functions it calls take at most
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Varchar], Type::Varchar),
+        (&[Type::Varchar, Type::Any], Type::Varchar),
+        (&[Type::Varchar, Type::Any, Type::Any], Type::Varchar),
+        (&[Type::Varchar, Type::Any, Type::Any, Type::Any], Type::Varchar),
+        (&[Type::Varchar, Type::Any, Type::Any, Type::Any, Type::Any], Type::Varchar),
+    ];
+}
+
+impl VScalar for Dispatch {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        dispatch::invoke(input, output)
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Pseudonymize function: replaces a real value with a fake one of the named
+// generator, picked by an HMAC of the value under a secret
+struct Pseudonymize;
+
+impl Overloads for Pseudonymize {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Any, Type::Varchar, Type::Varchar], Type::Varchar),
+        (&[Type::Any, Type::Varchar, Type::Varchar, Type::Varchar], Type::Varchar),
+    ];
+}
+
+impl VScalar for Pseudonymize {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut output_vec = output.flat_vector();
+        let values = key::encodings(input, 0)?;
+        let generators = args::strings(input, 1);
+        let secrets = args::strings(input, 2);
+        // Without a locale argument pseudonyms use the default one
+        let locales = match input.num_columns() {
+            3 => vec![Some(None); input.len()],
+            _ => args::strings(input, 3)
+                .into_iter()
+                .map(|code| code.map(|code| locale::find(&code)).transpose())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("fakeit_pseudonymize: {}", e))?,
+        };
+
+        let _stream = rng::lock();
+        let rows = values.into_iter().zip(generators).zip(secrets).zip(locales);
+        for (i, (((value, generator), secret), locale)) in rows.enumerate() {
+            let (Some(value), Some(generator), Some(secret), Some(locale)) = (value, generator, secret, locale) else {
+                output_vec.set_null(i);
+                continue;
+            };
+            let pseudonym = locale::with(locale, || pseudonym::pseudonymize(&value, &generator, &secret))
+                .map_err(|e| format!("fakeit_pseudonymize: {}", e))?;
+            output_vec.insert(i, pseudonym.as_str());
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+}
 
 // Boolean function
 boolean_scalar!(BoolRand, fakeit::bool_rand::bool);
@@ -378,162 +1734,435 @@ double_scalar!(AddressLatitude, || fakeit::address::latitude() as f64);
 double_scalar!(AddressLongitude, || fakeit::address::longitude() as f64);
 
 // Parameterized functions
-double_double_scalar!(AddressLatitudeInRange, |min, max| fakeit::address::latitude_in_range(min as f32, max as f32) as f64);
-double_double_scalar!(AddressLongitudeInRange, |min, max| fakeit::address::longitude_in_range(min as f32, max as f32) as f64);
+distribution_scalar!(AddressLatitudeInRange, "fakeit_address_latitude_in_range", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, |min, max| coordinate_in_range(min, max, 90.0));
+distribution_scalar!(AddressLongitudeInRange, "fakeit_address_longitude_in_range", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, |min, max| coordinate_in_range(min, max, 180.0));
+
+// fakeit's latitude_in_range and longitude_in_range return the same multiple of
+// `min` on every call, so the range is drawn here
+fn coordinate_in_range(min: f64, max: f64, limit: f64) -> Result<f64, String> {
+    for (bound, value) in [("min", min), ("max", max)] {
+        if !(-limit..=limit).contains(&value) {
+            return Err(format!("{} must be between -{} and {}, got {}", bound, limit, limit, value));
+        }
+    }
+    if min > max {
+        return Err("min must not be greater than max".to_string());
+    }
+    Ok(min + (max - min) * rng::unit())
+}
+
+// Fill-in pattern functions
+fill_scalar!(Numerify, "fakeit_numerify", pattern::DIGITS, pattern::numerify_with);
+fill_scalar!(Lexify, "fakeit_lexify", pattern::LETTERS, pattern::lexify);
+fill_scalar!(Bothify, "fakeit_bothify", pattern::LETTERS, pattern::bothify);
+
+// Distribution functions
+distribution_scalar!(Normal, "fakeit_normal", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, distribution::normal);
+distribution_scalar!(Lognormal, "fakeit_lognormal", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, distribution::lognormal);
+distribution_scalar!(Exponential, "fakeit_exponential", (Type::Double => f64) -> Type::Double, f64, distribution::exponential);
+distribution_scalar!(Poisson, "fakeit_poisson", (Type::Double => f64) -> Type::Bigint, i64, distribution::poisson);
+distribution_scalar!(Binomial, "fakeit_binomial", (Type::Bigint => i64, Type::Double => f64) -> Type::Bigint, i64, distribution::binomial);
+distribution_scalar!(Zipf, "fakeit_zipf", (Type::Bigint => i64, Type::Double => f64) -> Type::Bigint, i64, distribution::zipf);
+distribution_scalar!(Pareto, "fakeit_pareto", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, distribution::pareto);
+distribution_scalar!(Gamma, "fakeit_gamma", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, distribution::gamma);
+distribution_scalar!(Beta, "fakeit_beta", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, distribution::beta);
+distribution_scalar!(Float, "fakeit_float", (Type::Double => f64, Type::Double => f64) -> Type::Double, f64, distribution::uniform);
+distribution_scalar!(Bernoulli, "fakeit_bernoulli", (Type::Double => f64) -> Type::Bigint, i64, distribution::bernoulli);
 
 // Status code functions
 bigint_scalar!(StatusCodeSimple, || fakeit::status_code::simple() as i64);
 bigint_scalar!(StatusCodeGeneral, || fakeit::status_code::general() as i64);
 
-#[duckdb_entrypoint_c_api()]
-pub unsafe fn extension_entrypoint(con: Connection) -> Result<(), Box<dyn Error>> {
-    // Register all address functions
-    con.register_scalar_function::<AddressStreet>("fakeit_address_street")?;
-    con.register_scalar_function::<AddressCity>("fakeit_address_city")?;
-    con.register_scalar_function::<AddressState>("fakeit_address_state")?;
-    con.register_scalar_function::<AddressZip>("fakeit_address_zip")?;
-    con.register_scalar_function::<AddressCountry>("fakeit_address_country")?;
-    con.register_scalar_function::<AddressStreetNumber>("fakeit_address_street_number")?;
-    con.register_scalar_function::<AddressStreetPrefix>("fakeit_address_street_prefix")?;
-    con.register_scalar_function::<AddressStreetName>("fakeit_address_street_name")?;
-    con.register_scalar_function::<AddressStreetSuffix>("fakeit_address_street_suffix")?;
-    con.register_scalar_function::<AddressStateAbr>("fakeit_address_state_abr")?;
-    con.register_scalar_function::<AddressCountryAbr>("fakeit_address_country_abr")?;
-    con.register_scalar_function::<AddressLatitude>("fakeit_address_latitude")?;
-    con.register_scalar_function::<AddressLongitude>("fakeit_address_longitude")?;
-    con.register_scalar_function::<AddressLatitudeInRange>("fakeit_address_latitude_in_range")?;
-    con.register_scalar_function::<AddressLongitudeInRange>("fakeit_address_longitude_in_range")?;
-
-    con.register_scalar_function::<NameFirst>("fakeit_name_first")?;
-    con.register_scalar_function::<NameLast>("fakeit_name_last")?;
-    con.register_scalar_function::<NameFull>("fakeit_name_full")?;
-    con.register_scalar_function::<NamePrefix>("fakeit_name_prefix")?;
-    con.register_scalar_function::<NameSuffix>("fakeit_name_suffix")?;
-
-    con.register_scalar_function::<CompanyCompany>("fakeit_company_company")?;
-    con.register_scalar_function::<CompanyBs>("fakeit_company_bs")?;
-    con.register_scalar_function::<CompanySuffix>("fakeit_company_company_suffix")?;
-    con.register_scalar_function::<CompanyBuzzword>("fakeit_company_buzzword")?;
-
-    con.register_scalar_function::<ContactEmail>("fakeit_contact_email")?;
-    con.register_scalar_function::<ContactPhone>("fakeit_contact_phone")?;
-    con.register_scalar_function::<ContactPhoneFormatted>("fakeit_contact_phone_formatted")?;
-
-    con.register_scalar_function::<InternetIpv4Address>("fakeit_internet_ipv4_address")?;
-    con.register_scalar_function::<InternetIpv6Address>("fakeit_internet_ipv6_address")?;
-    con.register_scalar_function::<InternetDomainName>("fakeit_internet_domain_name")?;
-    con.register_scalar_function::<InternetDomainSuffix>("fakeit_internet_domain_suffix")?;
-    con.register_scalar_function::<InternetUsername>("fakeit_internet_username")?;
-    con.register_scalar_function::<InternetHttpMethod>("fakeit_internet_http_method")?;
-    con.register_scalar_function::<InternetMacAddress>("fakeit_internet_mac_address")?;
-
-    con.register_scalar_function::<UuidV1>("fakeit_uuid_v1")?;
-    con.register_scalar_function::<UuidV4>("fakeit_uuid_v4")?;
-
-    con.register_scalar_function::<AnimalPetName>("fakeit_animal_pet_name")?;
-    con.register_scalar_function::<AnimalAnimal>("fakeit_animal_animal")?;
-    con.register_scalar_function::<AnimalFarm>("fakeit_animal_farm")?;
-    con.register_scalar_function::<AnimalCat>("fakeit_animal_cat")?;
-    con.register_scalar_function::<AnimalDog>("fakeit_animal_dog")?;
-
-    con.register_scalar_function::<BeerName>("fakeit_beer_name")?;
-    con.register_scalar_function::<BeerStyle>("fakeit_beer_style")?;
-    con.register_scalar_function::<BeerHop>("fakeit_beer_hop")?;
-    con.register_scalar_function::<BeerYeast>("fakeit_beer_yeast")?;
-    con.register_scalar_function::<BeerMalt>("fakeit_beer_malt")?;
-    con.register_scalar_function::<BeerIbu>("fakeit_beer_ibu")?;
-    con.register_scalar_function::<BeerAlcohol>("fakeit_beer_alcohol")?;
-    con.register_scalar_function::<BeerBlg>("fakeit_beer_blg")?;
-
-    con.register_scalar_function::<ColorFull>("fakeit_color_full")?;
-    con.register_scalar_function::<ColorHex>("fakeit_color_hex")?;
-    con.register_scalar_function::<ColorSafe>("fakeit_color_safe")?;
-    con.register_scalar_function::<ColorRgb>("fakeit_color_rgb")?;
-
-    con.register_scalar_function::<CurrencyShort>("fakeit_currency_short")?;
-    con.register_scalar_function::<CurrencyLong>("fakeit_currency_long")?;
-    con.register_scalar_function::<CurrencyPrice>("fakeit_currency_price")?;
-
-    con.register_scalar_function::<DateTimeMonth>("fakeit_datetime_month")?;
-    con.register_scalar_function::<DateTimeDay>("fakeit_datetime_day")?;
-    con.register_scalar_function::<DateTimeWeekDay>("fakeit_datetime_week_day")?;
-    con.register_scalar_function::<DateTimeTimezone>("fakeit_datetime_timezone")?;
-    con.register_scalar_function::<DateTimeTimezoneFull>("fakeit_datetime_timezone_full")?;
-    con.register_scalar_function::<DateTimeTimezoneAbv>("fakeit_datetime_timezone_abv")?;
-    con.register_scalar_function::<DateTimeYear>("fakeit_datetime_year")?;
-    con.register_scalar_function::<DateTimeHour>("fakeit_datetime_hour")?;
-    con.register_scalar_function::<DateTimeMinute>("fakeit_datetime_minute")?;
-    con.register_scalar_function::<DateTimeSecond>("fakeit_datetime_second")?;
-    con.register_scalar_function::<DateTimeNanosecond>("fakeit_datetime_nanosecond")?;
-    con.register_scalar_function::<DateTimeTimezoneOffset>("fakeit_datetime_timezone_offset")?;
-    con.register_scalar_function::<DateTimeDate>("fakeit_datetime_date")?;
-
-    con.register_scalar_function::<FileExtension>("fakeit_file_extension")?;
-    con.register_scalar_function::<FileMimeType>("fakeit_file_mime_type")?;
-
-    con.register_scalar_function::<HackerPhrase>("fakeit_hacker_phrase")?;
-    con.register_scalar_function::<HackerAbbreviation>("fakeit_hacker_abbreviation")?;
-    con.register_scalar_function::<HackerAdjective>("fakeit_hacker_adjective")?;
-    con.register_scalar_function::<HackerNoun>("fakeit_hacker_noun")?;
-    con.register_scalar_function::<HackerVerb>("fakeit_hacker_verb")?;
-    con.register_scalar_function::<HackerIngverb>("fakeit_hacker_ingverb")?;
-
-    con.register_scalar_function::<HipsterWord>("fakeit_hipster_word")?;
-    con.register_scalar_function::<HipsterSentence>("fakeit_hipster_sentence")?;
-    con.register_scalar_function::<HipsterParagraph>("fakeit_hipster_paragraph")?;
-
-    con.register_scalar_function::<ImageUrl>("fakeit_image_url")?;
-
-    con.register_scalar_function::<JobTitle>("fakeit_job_title")?;
-    con.register_scalar_function::<JobDescriptor>("fakeit_job_descriptor")?;
-    con.register_scalar_function::<JobLevel>("fakeit_job_level")?;
-
-    con.register_scalar_function::<LanguageRandom>("fakeit_language_random")?;
-    con.register_scalar_function::<LanguageAbbreviation>("fakeit_language_abbreviation")?;
-    con.register_scalar_function::<LanguageProgramming>("fakeit_language_programming")?;
-
-    con.register_scalar_function::<LogLevelGeneral>("fakeit_log_level_general")?;
-    con.register_scalar_function::<LogLevelSyslog>("fakeit_log_level_syslog")?;
-    con.register_scalar_function::<LogLevelApache>("fakeit_log_level_apache")?;
-
-    con.register_scalar_function::<PasswordGenerate>("fakeit_password_generate")?;
-
-    con.register_scalar_function::<PaymentCreditCardType>("fakeit_payment_credit_card_type")?;
-    con.register_scalar_function::<PaymentCreditCardNumber>("fakeit_payment_credit_card_number")?;
-    con.register_scalar_function::<PaymentCreditCardExp>("fakeit_payment_credit_card_exp")?;
-    con.register_scalar_function::<PaymentCreditCardCvv>("fakeit_payment_credit_card_cvv")?;
-    con.register_scalar_function::<PaymentCreditCardLuhnNumber>("fakeit_payment_credit_card_luhn_number")?;
-
-    con.register_scalar_function::<PersonSsn>("fakeit_person_ssn")?;
-    con.register_scalar_function::<PersonGender>("fakeit_person_gender")?;
-
-    con.register_scalar_function::<UserAgentChrome>("fakeit_user_agent_chrome")?;
-    con.register_scalar_function::<UserAgentFirefox>("fakeit_user_agent_firefox")?;
-    con.register_scalar_function::<UserAgentSafari>("fakeit_user_agent_safari")?;
-    con.register_scalar_function::<UserAgentOpera>("fakeit_user_agent_opera")?;
-    con.register_scalar_function::<UserAgentLinuxPlatformToken>("fakeit_user_agent_linux_platform_token")?;
-    con.register_scalar_function::<UserAgentMacPlatformToken>("fakeit_user_agent_mac_platform_token")?;
-    con.register_scalar_function::<UserAgentWindowsPlatformToken>("fakeit_user_agent_windows_platform_token")?;
-    con.register_scalar_function::<UserAgentRandomPlatform>("fakeit_user_agent_random_platform")?;
-
-    con.register_scalar_function::<VehicleType>("fakeit_vehicle_vehicle_type")?;
-    con.register_scalar_function::<VehicleFuel>("fakeit_vehicle_fuel")?;
-    con.register_scalar_function::<VehicleTransmissionGear>("fakeit_vehicle_transmission_gear")?;
-    con.register_scalar_function::<VehicleCarMaker>("fakeit_vehicle_car_maker")?;
-    con.register_scalar_function::<VehicleCarModel>("fakeit_vehicle_car_model")?;
-
-    con.register_scalar_function::<WordsWord>("fakeit_words_word")?;
-    con.register_scalar_function::<WordsSentence>("fakeit_words_sentence")?;
-    con.register_scalar_function::<WordsParagraph>("fakeit_words_paragraph")?;
-    con.register_scalar_function::<WordsQuestion>("fakeit_words_question")?;
-    con.register_scalar_function::<WordsQuote>("fakeit_words_quote")?;
-
-    con.register_scalar_function::<GeneratorGenerate>("fakeit_generator_generate")?;
-
-    con.register_scalar_function::<BoolRand>("fakeit_bool")?;
-
-    con.register_scalar_function::<StatusCodeSimple>("fakeit_status_code_simple")?;
-    con.register_scalar_function::<StatusCodeGeneral>("fakeit_status_code_general")?;
-
-    Ok(())
+// Seed function: reseeds the shared stream so that subsequent queries are reproducible
+struct Seed;
+
+impl Overloads for Seed {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[Type::Bigint], Type::Bigint),
+    ];
+}
+
+impl VScalar for Seed {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut output_vec = output.flat_vector();
+
+        let _stream = rng::lock();
+        for (i, seed) in args::column::<i64>(input, 0).into_iter().enumerate() {
+            let Some(seed) = seed else {
+                output_vec.set_null(i);
+                continue;
+            };
+            rng::set_seed(seed);
+            output_vec.as_mut_slice::<i64>()[i] = seed;
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Locale function: switches the name, address, phone, company and ID generators
+// to another locale's data and returns its canonical code; without an argument
+// it returns the current locale
+struct Locale;
+
+impl Overloads for Locale {
+    const OVERLOADS: &'static [Overload] = &[
+        (&[], Type::Varchar),
+        (&[Type::Varchar], Type::Varchar),
+    ];
+}
+
+impl VScalar for Locale {
+    type State = ();
+
+    unsafe fn invoke(
+        _state: &Self::State,
+        input: &mut DataChunkHandle,
+        output: &mut dyn WritableVector,
+    ) -> Result<(), Box<dyn Error>> {
+        let len = input.len();
+        let mut output_vec = output.flat_vector();
+
+        let _stream = rng::lock();
+        if input.num_columns() == 0 {
+            for i in 0..len {
+                output_vec.insert(i, locale::current_code());
+            }
+            return Ok(());
+        }
+
+        for (i, code) in args::strings(input, 0).into_iter().enumerate() {
+            let Some(code) = code else {
+                output_vec.set_null(i);
+                continue;
+            };
+            let canonical = locale::set(&code).map_err(|e| format!("fakeit_locale: {}", e))?;
+            output_vec.insert(i, canonical);
+        }
+        Ok(())
+    }
+
+    fn signatures() -> Vec<ScalarFunctionSignature> {
+        functions::signatures(Self::OVERLOADS)
+    }
+
+    fn volatile() -> bool {
+        true
+    }
+}
+
+// Builds the FUNCTIONS list: each entry pairs a SQL name and category with the
+// struct implementing it; table functions come last, in the category "table"
+macro_rules! functions {
+    (
+        $($category:literal => [$($name:ident: $sql_name:literal),+ $(,)?],)+
+        table => [$($table:ty: $table_name:literal),+ $(,)?] $(,)?
+    ) => {
+        &[$($(functions::Function {
+            name: $sql_name,
+            category: $category,
+            overloads: <$name as Overloads>::OVERLOADS,
+            named_parameters: Vec::new,
+            generator: <$name as Overloads>::GENERATOR,
+            invoke: Some(<$name as VScalar>::invoke),
+            register: |con, raw, _| <$name as Overloads>::register(con, raw, $sql_name),
+        },)+)+
+        $(functions::Function {
+            name: $table_name,
+            category: "table",
+            overloads: &[(<$table as functions::Parameters>::PARAMETERS, Type::Table)],
+            named_parameters: <$table as functions::Parameters>::named_parameters,
+            generator: None,
+            invoke: None,
+            register: |con, _, catalog| <$table as functions::Parameters>::register(con, $table_name, catalog),
+        },)+]
+    };
+}
+
+// Every function with its category. The entrypoint registers exactly these,
+// and fakeit_functions() lists them.
+static FUNCTIONS: &[functions::Function] = functions! {
+    "address" => [
+        AddressStreet: "fakeit_address_street",
+        AddressCity: "fakeit_address_city",
+        AddressState: "fakeit_address_state",
+        AddressZip: "fakeit_address_zip",
+        AddressCountry: "fakeit_address_country",
+        AddressStreetNumber: "fakeit_address_street_number",
+        AddressStreetPrefix: "fakeit_address_street_prefix",
+        AddressStreetName: "fakeit_address_street_name",
+        AddressStreetSuffix: "fakeit_address_street_suffix",
+        AddressStateAbr: "fakeit_address_state_abr",
+        AddressCountryAbr: "fakeit_address_country_abr",
+        AddressLatitude: "fakeit_address_latitude",
+        AddressLongitude: "fakeit_address_longitude",
+        AddressLatitudeInRange: "fakeit_address_latitude_in_range",
+        AddressLongitudeInRange: "fakeit_address_longitude_in_range",
+    ],
+    "name" => [
+        NameFirst: "fakeit_name_first",
+        NameLast: "fakeit_name_last",
+        NameFull: "fakeit_name_full",
+        NamePrefix: "fakeit_name_prefix",
+        NameSuffix: "fakeit_name_suffix",
+    ],
+    "company" => [
+        CompanyCompany: "fakeit_company_company",
+        CompanyBs: "fakeit_company_bs",
+        CompanySuffix: "fakeit_company_company_suffix",
+        CompanyBuzzword: "fakeit_company_buzzword",
+    ],
+    "contact" => [
+        ContactEmail: "fakeit_contact_email",
+        ContactPhone: "fakeit_contact_phone",
+        ContactPhoneFormatted: "fakeit_contact_phone_formatted",
+    ],
+    "internet" => [
+        InternetIpv4Address: "fakeit_internet_ipv4_address",
+        InternetIpv6Address: "fakeit_internet_ipv6_address",
+        InternetDomainName: "fakeit_internet_domain_name",
+        InternetDomainSuffix: "fakeit_internet_domain_suffix",
+        InternetUsername: "fakeit_internet_username",
+        InternetHttpMethod: "fakeit_internet_http_method",
+        InternetMacAddress: "fakeit_internet_mac_address",
+    ],
+    "uuid" => [
+        UuidV1: "fakeit_uuid_v1",
+        UuidV4: "fakeit_uuid_v4",
+        UuidV7: "fakeit_uuid_v7",
+        UuidV5: "fakeit_uuid_v5",
+    ],
+    "animal" => [
+        AnimalPetName: "fakeit_animal_pet_name",
+        AnimalAnimal: "fakeit_animal_animal",
+        AnimalFarm: "fakeit_animal_farm",
+        AnimalCat: "fakeit_animal_cat",
+        AnimalDog: "fakeit_animal_dog",
+    ],
+    "beer" => [
+        BeerName: "fakeit_beer_name",
+        BeerStyle: "fakeit_beer_style",
+        BeerHop: "fakeit_beer_hop",
+        BeerYeast: "fakeit_beer_yeast",
+        BeerMalt: "fakeit_beer_malt",
+        BeerIbu: "fakeit_beer_ibu",
+        BeerAlcohol: "fakeit_beer_alcohol",
+        BeerBlg: "fakeit_beer_blg",
+    ],
+    "color" => [
+        ColorFull: "fakeit_color_full",
+        ColorHex: "fakeit_color_hex",
+        ColorSafe: "fakeit_color_safe",
+        ColorRgb: "fakeit_color_rgb",
+        ColorHsl: "fakeit_color_hsl",
+        ColorCmyk: "fakeit_color_cmyk",
+        ColorPalette: "fakeit_color_palette",
+    ],
+    "currency" => [
+        CurrencyShort: "fakeit_currency_short",
+        CurrencyLong: "fakeit_currency_long",
+        CurrencyPrice: "fakeit_currency_price",
+    ],
+    "datetime" => [
+        DateTimeMonth: "fakeit_datetime_month",
+        DateTimeDay: "fakeit_datetime_day",
+        DateTimeWeekDay: "fakeit_datetime_week_day",
+        DateTimeTimezone: "fakeit_datetime_timezone",
+        DateTimeTimezoneFull: "fakeit_datetime_timezone_full",
+        DateTimeTimezoneAbv: "fakeit_datetime_timezone_abv",
+        DateTimeYear: "fakeit_datetime_year",
+        DateTimeHour: "fakeit_datetime_hour",
+        DateTimeMinute: "fakeit_datetime_minute",
+        DateTimeSecond: "fakeit_datetime_second",
+        DateTimeNanosecond: "fakeit_datetime_nanosecond",
+        DateTimeTimezoneOffset: "fakeit_datetime_timezone_offset",
+        DateTimeDate: "fakeit_datetime_date",
+        Date: "fakeit_date",
+        Timestamp: "fakeit_timestamp",
+        TimestampTz: "fakeit_timestamptz",
+    ],
+    "file" => [
+        FileExtension: "fakeit_file_extension",
+        FileMimeType: "fakeit_file_mime_type",
+    ],
+    "hacker" => [
+        HackerPhrase: "fakeit_hacker_phrase",
+        HackerAbbreviation: "fakeit_hacker_abbreviation",
+        HackerAdjective: "fakeit_hacker_adjective",
+        HackerNoun: "fakeit_hacker_noun",
+        HackerVerb: "fakeit_hacker_verb",
+        HackerIngverb: "fakeit_hacker_ingverb",
+    ],
+    "hipster" => [
+        HipsterWord: "fakeit_hipster_word",
+        HipsterSentence: "fakeit_hipster_sentence",
+        HipsterParagraph: "fakeit_hipster_paragraph",
+    ],
+    "image" => [
+        ImageUrl: "fakeit_image_url",
+    ],
+    "job" => [
+        JobTitle: "fakeit_job_title",
+        JobDescriptor: "fakeit_job_descriptor",
+        JobLevel: "fakeit_job_level",
+    ],
+    "language" => [
+        LanguageRandom: "fakeit_language_random",
+        LanguageAbbreviation: "fakeit_language_abbreviation",
+        LanguageProgramming: "fakeit_language_programming",
+    ],
+    "log_level" => [
+        LogLevelGeneral: "fakeit_log_level_general",
+        LogLevelSyslog: "fakeit_log_level_syslog",
+        LogLevelApache: "fakeit_log_level_apache",
+    ],
+    "password" => [
+        PasswordGenerate: "fakeit_password_generate",
+    ],
+    "payment" => [
+        PaymentCreditCardType: "fakeit_payment_credit_card_type",
+        PaymentCreditCardNumber: "fakeit_payment_credit_card_number",
+        PaymentCreditCardExp: "fakeit_payment_credit_card_exp",
+        PaymentCreditCardCvv: "fakeit_payment_credit_card_cvv",
+        PaymentCreditCardLuhnNumber: "fakeit_payment_credit_card_luhn_number",
+    ],
+    "person" => [
+        PersonSsn: "fakeit_person_ssn",
+        PersonGender: "fakeit_person_gender",
+    ],
+    "user_agent" => [
+        UserAgentChrome: "fakeit_user_agent_chrome",
+        UserAgentFirefox: "fakeit_user_agent_firefox",
+        UserAgentSafari: "fakeit_user_agent_safari",
+        UserAgentOpera: "fakeit_user_agent_opera",
+        UserAgentLinuxPlatformToken: "fakeit_user_agent_linux_platform_token",
+        UserAgentMacPlatformToken: "fakeit_user_agent_mac_platform_token",
+        UserAgentWindowsPlatformToken: "fakeit_user_agent_windows_platform_token",
+        UserAgentRandomPlatform: "fakeit_user_agent_random_platform",
+    ],
+    "vehicle" => [
+        VehicleType: "fakeit_vehicle_vehicle_type",
+        VehicleFuel: "fakeit_vehicle_fuel",
+        VehicleTransmissionGear: "fakeit_vehicle_transmission_gear",
+        VehicleCarMaker: "fakeit_vehicle_car_maker",
+        VehicleCarModel: "fakeit_vehicle_car_model",
+    ],
+    "words" => [
+        WordsWord: "fakeit_words_word",
+        WordsSentence: "fakeit_words_sentence",
+        WordsParagraph: "fakeit_words_paragraph",
+        WordsQuestion: "fakeit_words_question",
+        WordsQuote: "fakeit_words_quote",
+    ],
+    "generator" => [
+        GeneratorGenerate: "fakeit_generator_generate",
+    ],
+    "bool" => [
+        BoolRand: "fakeit_bool",
+    ],
+    "status_code" => [
+        StatusCodeSimple: "fakeit_status_code_simple",
+        StatusCodeGeneral: "fakeit_status_code_general",
+    ],
+    "distribution" => [
+        Normal: "fakeit_normal",
+        Lognormal: "fakeit_lognormal",
+        Exponential: "fakeit_exponential",
+        Poisson: "fakeit_poisson",
+        Binomial: "fakeit_binomial",
+        Zipf: "fakeit_zipf",
+        Pareto: "fakeit_pareto",
+        Gamma: "fakeit_gamma",
+        Beta: "fakeit_beta",
+        Bernoulli: "fakeit_bernoulli",
+    ],
+    "pattern" => [
+        Regex: "fakeit_regex",
+        Numerify: "fakeit_numerify",
+        Lexify: "fakeit_lexify",
+        Bothify: "fakeit_bothify",
+    ],
+    "number" => [
+        Int: "fakeit_int",
+        Float: "fakeit_float",
+        Decimal: "fakeit_decimal",
+        Digits: "fakeit_digits",
+    ],
+    "choice" => [
+        Choice: "fakeit_choice",
+        WeightedChoice: "fakeit_weighted_choice",
+    ],
+    "null" => [Nullable: "fakeit_nullable"],
+    "pseudonym" => [
+        Pseudonymize: "fakeit_pseudonymize",
+        MaskLike: "fakeit_mask_like",
+    ],
+    "unique" => [
+        Unique: "fakeit_unique",
+    ],
+    "dispatch" => [Dispatch: "fakeit"],
+    "settings" => [
+        Seed: "fakeit_seed",
+        Locale: "fakeit_locale",
+    ],
+    table => [
+        table::FakeitTable: "fakeit",
+        persons::PersonsTable: "fakeit_persons",
+        fill::FillTable: "fakeit_fill",
+        children::ChildrenTable: "fakeit_children",
+        timeseries::TimeseriesTable: "fakeit_timeseries",
+        functions::FunctionsTable: "fakeit_functions",
+    ],
+};
+
+/// Registers every fakeit function. This is what #[duckdb_entrypoint_c_api]
+/// generates, except that it keeps the database handle, which fakeit_unique
+/// needs to register through the C API (see unique.rs).
+///
+/// # Safety
+///
+/// Must only be called by DuckDB while loading the extension.
+#[no_mangle]
+pub unsafe extern "C" fn fakeit_init_c_api(
+    info: ffi::duckdb_extension_info,
+    access: *const ffi::duckdb_extension_access,
+) -> bool {
+    match extension_entrypoint(info, access) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            let message = std::ffi::CString::new(e.to_string())
+                .unwrap_or_else(|_| c"fakeit: the extension failed to load".to_owned());
+            (*access).set_error.unwrap()(info, message.as_ptr());
+            false
+        }
+    }
+}
+
+unsafe fn extension_entrypoint(
+    info: ffi::duckdb_extension_info,
+    access: *const ffi::duckdb_extension_access,
+) -> Result<bool, Box<dyn Error>> {
+    // The Makefile builds with the DuckDB version it targets as the minimum,
+    // as the macro reads it
+    let version = option_env!("DUCKDB_EXTENSION_MIN_DUCKDB_VERSION").unwrap_or("v1.2.0");
+    if !ffi::duckdb_rs_extension_api_init(info, access, version)? {
+        return Ok(false);
+    }
+    let database: ffi::duckdb_database = *(*access).get_database.unwrap()(info);
+    let con = Connection::open_from_raw(database.cast())?;
+    functions::register_all(&con, database)?;
+    Ok(true)
 }
@@ -0,0 +1,360 @@
+// Locale-aware generators.
+//
+// The fakeit crate only knows US English data. `fakeit_locale('de_DE')` switches
+// the name, address, phone, company and ID generators over to one of the
+// bundled dictionaries below; 'en_US' (the default) goes back to fakeit's own
+// data. Like the seed, the locale is process-wide, except for keyed values and
+// pseudonyms: those must not change with another session's fakeit_locale, so
+// they use the default unless the call names a locale itself (see `with`).
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{pattern, rng};
+
+#[path = "locales/dach.rs"]
+mod dach;
+#[path = "locales/fr.rs"]
+mod fr;
+#[path = "locales/ja.rs"]
+mod ja;
+#[path = "locales/pt_br.rs"]
+mod pt_br;
+
+pub struct Locale {
+    pub code: &'static str,
+    pub male_first: &'static [&'static str],
+    pub female_first: &'static [&'static str],
+    pub last: &'static [&'static str],
+    // Latin spellings, index-aligned with the name lists, for locales whose
+    // names are not written in Latin script; empty otherwise
+    pub male_first_latin: &'static [&'static str],
+    pub female_first_latin: &'static [&'static str],
+    pub last_latin: &'static [&'static str],
+    pub family_name_first: bool,
+    // Empty where names carry no prefix
+    pub male_prefixes: &'static [&'static str],
+    pub female_prefixes: &'static [&'static str],
+    pub street_names: &'static [&'static str],
+    pub street_number: fn() -> String,
+    pub street: fn(name: &str, number: &str) -> String,
+    pub cities: &'static [&'static str],
+    // (name, abbreviation)
+    pub states: &'static [(&'static str, &'static str)],
+    pub postal_code: fn() -> String,
+    // (name, ISO 3166 code)
+    pub country: (&'static str, &'static str),
+    // Patterns for pattern::numerify
+    pub phone_formats: &'static [&'static str],
+    pub company_suffixes: &'static [&'static str],
+    pub company: fn(last: &str, other: &str, suffix: &str) -> String,
+    pub email_domains: &'static [&'static str],
+    pub national_id: fn() -> String,
+}
+
+pub struct Name {
+    pub text: &'static str,
+    // Lowercase ASCII spelling, e.g. for email addresses
+    pub ascii: String,
+}
+
+static LOCALES: &[&Locale] = &[
+    &dach::DE_DE,
+    &dach::DE_AT,
+    &dach::DE_CH,
+    &fr::FR_FR,
+    &ja::JA_JP,
+    &pt_br::PT_BR,
+];
+
+pub const DEFAULT: &str = "en_US";
+
+// 0 for the default, otherwise one past the index into LOCALES
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // The locale of the call in progress on this thread, in place of CURRENT
+    static SCOPED: Cell<Option<Option<&'static Locale>>> = const { Cell::new(None) };
+}
+
+pub fn current() -> Option<&'static Locale> {
+    if let Some(locale) = SCOPED.with(Cell::get) {
+        return locale;
+    }
+    match CURRENT.load(Ordering::Relaxed) {
+        0 => None,
+        i => Some(LOCALES[i - 1]),
+    }
+}
+
+// Run `f` with `locale` (None for the default) as the current locale of this
+// thread only
+pub fn with<T>(locale: Option<&'static Locale>, f: impl FnOnce() -> T) -> T {
+    let saved = SCOPED.with(|scoped| scoped.replace(Some(locale)));
+    let result = f();
+    SCOPED.with(|scoped| scoped.set(saved));
+    result
+}
+
+// Run `f` with the locale picked by an enclosing `with`, or the default, so
+// that keyed values only depend on their arguments
+pub fn pinned<T>(f: impl FnOnce() -> T) -> T {
+    match SCOPED.with(Cell::get) {
+        Some(_) => f(),
+        None => with(None, f),
+    }
+}
+
+pub fn current_code() -> &'static str {
+    current().map_or(DEFAULT, |l| l.code)
+}
+
+// Accepts "de_DE", "de-DE" and bare languages ("de"), in any case, and returns
+// the canonical code
+pub fn set(code: &str) -> Result<&'static str, String> {
+    let index = position(code)?;
+    CURRENT.store(index, Ordering::Relaxed);
+    Ok(if index == 0 { DEFAULT } else { LOCALES[index - 1].code })
+}
+
+// The locale named by `code` as `set` accepts it, None for the default
+pub fn find(code: &str) -> Result<Option<&'static Locale>, String> {
+    Ok(match position(code)? {
+        0 => None,
+        i => Some(LOCALES[i - 1]),
+    })
+}
+
+// 0 for the default, otherwise one past the index into LOCALES
+fn position(code: &str) -> Result<usize, String> {
+    let normalized = code.trim().replace('-', "_").to_ascii_lowercase();
+    if normalized == "en" || normalized == DEFAULT.to_ascii_lowercase() {
+        return Ok(0);
+    }
+    let index = LOCALES
+        .iter()
+        .position(|l| l.code.to_ascii_lowercase() == normalized)
+        .or_else(|| LOCALES.iter().position(|l| l.code[..2] == normalized))
+        .ok_or_else(|| format!("unknown locale '{}', expected one of {}", code, supported().join(", ")))?;
+    Ok(index + 1)
+}
+
+pub fn supported() -> Vec<&'static str> {
+    std::iter::once(DEFAULT).chain(LOCALES.iter().map(|l| l.code)).collect()
+}
+
+impl Locale {
+    pub fn first_name(&self, female: bool) -> Name {
+        if female {
+            pick_name(self.female_first, self.female_first_latin)
+        } else {
+            pick_name(self.male_first, self.male_first_latin)
+        }
+    }
+
+    pub fn last_name(&self) -> Name {
+        pick_name(self.last, self.last_latin)
+    }
+
+    pub fn prefix(&self, female: bool) -> &'static str {
+        let prefixes = if female { self.female_prefixes } else { self.male_prefixes };
+        if prefixes.is_empty() {
+            ""
+        } else {
+            rng::pick(prefixes)
+        }
+    }
+
+    pub fn full_name(&self, first: &str, last: &str) -> String {
+        if self.family_name_first {
+            format!("{} {}", last, first)
+        } else {
+            format!("{} {}", first, last)
+        }
+    }
+}
+
+fn pick_name(names: &'static [&'static str], latin: &'static [&'static str]) -> Name {
+    let i = rng::below(names.len() as u64) as usize;
+    let spelling = if latin.is_empty() { names[i] } else { latin[i] };
+    Name {
+        text: names[i],
+        ascii: ascii(spelling),
+    }
+}
+
+// Lowercase ASCII transliteration of a Latin-script name, dropping anything
+// that cannot appear in the local part of an email address
+pub fn ascii(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    for c in name.to_lowercase().chars() {
+        let replacement = match c {
+            'ä' | 'æ' => "ae",
+            'ö' | 'œ' => "oe",
+            'ü' => "ue",
+            'ß' => "ss",
+            'à' | 'á' | 'â' | 'ã' | 'å' => "a",
+            'ç' => "c",
+            'è' | 'é' | 'ê' | 'ë' => "e",
+            'ì' | 'í' | 'î' | 'ï' => "i",
+            'ñ' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' => "o",
+            'ù' | 'ú' | 'û' => "u",
+            'ý' | 'ÿ' => "y",
+            c if c.is_ascii_alphanumeric() => {
+                out.push(c);
+                continue;
+            }
+            _ => continue,
+        };
+        out.push_str(replacement);
+    }
+    out
+}
+
+// Generators behind the scalar functions: the current locale's data, or
+// fakeit's US data by default
+
+pub fn name_first() -> String {
+    match current() {
+        Some(l) => l.first_name(rng::below(2) == 0).text.to_string(),
+        None => fakeit::name::first(),
+    }
+}
+
+pub fn name_last() -> String {
+    match current() {
+        Some(l) => l.last_name().text.to_string(),
+        None => fakeit::name::last(),
+    }
+}
+
+pub fn name_full() -> String {
+    match current() {
+        Some(l) => {
+            let first = l.first_name(rng::below(2) == 0);
+            l.full_name(first.text, l.last_name().text)
+        }
+        None => fakeit::name::full(),
+    }
+}
+
+pub fn name_prefix() -> String {
+    match current() {
+        Some(l) => l.prefix(rng::below(2) == 0).to_string(),
+        None => fakeit::name::prefix(),
+    }
+}
+
+pub fn address_street() -> String {
+    match current() {
+        Some(l) => (l.street)(rng::pick(l.street_names), &(l.street_number)()),
+        None => fakeit::address::street(),
+    }
+}
+
+pub fn address_street_number() -> String {
+    match current() {
+        Some(l) => (l.street_number)(),
+        None => fakeit::address::street_number(),
+    }
+}
+
+pub fn address_street_name() -> String {
+    match current() {
+        Some(l) => rng::pick(l.street_names).to_string(),
+        None => fakeit::address::street_name(),
+    }
+}
+
+pub fn address_city() -> String {
+    match current() {
+        Some(l) => rng::pick(l.cities).to_string(),
+        None => fakeit::address::city(),
+    }
+}
+
+pub fn address_state() -> String {
+    match current() {
+        Some(l) => rng::pick(l.states).0.to_string(),
+        None => fakeit::address::state(),
+    }
+}
+
+pub fn address_state_abr() -> String {
+    match current() {
+        Some(l) => rng::pick(l.states).1.to_string(),
+        None => fakeit::address::state_abr(),
+    }
+}
+
+pub fn address_zip() -> String {
+    match current() {
+        Some(l) => (l.postal_code)(),
+        None => fakeit::address::zip(),
+    }
+}
+
+pub fn address_country() -> String {
+    match current() {
+        Some(l) => l.country.0.to_string(),
+        None => fakeit::address::country(),
+    }
+}
+
+pub fn address_country_abr() -> String {
+    match current() {
+        Some(l) => l.country.1.to_string(),
+        None => fakeit::address::country_abr(),
+    }
+}
+
+pub fn contact_phone() -> String {
+    match current() {
+        // The formatted number without separators, keeping a leading '+'
+        Some(l) => pattern::numerify(rng::pick(l.phone_formats))
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '+')
+            .collect(),
+        None => fakeit::contact::phone(),
+    }
+}
+
+pub fn contact_phone_formatted() -> String {
+    match current() {
+        Some(l) => pattern::numerify(rng::pick(l.phone_formats)),
+        None => fakeit::contact::phone_formatted(),
+    }
+}
+
+pub fn contact_email() -> String {
+    match current() {
+        Some(l) => {
+            let first = l.first_name(rng::below(2) == 0);
+            let last = l.last_name();
+            format!("{}.{}@{}", first.ascii, last.ascii, rng::pick(l.email_domains))
+        }
+        None => fakeit::contact::email(),
+    }
+}
+
+pub fn company_company() -> String {
+    match current() {
+        Some(l) => (l.company)(l.last_name().text, l.last_name().text, rng::pick(l.company_suffixes)),
+        None => fakeit::company::company(),
+    }
+}
+
+pub fn company_suffix() -> String {
+    match current() {
+        Some(l) => rng::pick(l.company_suffixes).to_string(),
+        None => fakeit::company::company_suffix(),
+    }
+}
+
+pub fn person_ssn() -> String {
+    match current() {
+        Some(l) => (l.national_id)(),
+        None => fakeit::person::ssn(),
+    }
+}
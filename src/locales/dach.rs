@@ -0,0 +1,290 @@
+// Germany, Austria and Switzerland.
+
+use super::{rng, Locale};
+
+static MALE_FIRST: &[&str] = &[
+    "Alexander", "Andreas", "Ben", "Christian", "Daniel", "David", "Elias", "Felix", "Florian", "Frank", "Jan",
+    "Jonas", "Jörg", "Julian", "Jürgen", "Kai", "Klaus", "Lars", "Leon", "Lukas", "Markus", "Martin", "Matthias",
+    "Max", "Michael", "Moritz", "Niklas", "Noah", "Paul", "Peter", "Philipp", "Ralf", "Sebastian", "Stefan",
+    "Thomas", "Tim", "Tobias", "Uwe", "Wolfgang", "Yannick",
+];
+
+static FEMALE_FIRST: &[&str] = &[
+    "Andrea", "Anja", "Anna", "Birgit", "Claudia", "Clara", "Emilia", "Emma", "Franziska", "Hannah", "Heike",
+    "Ines", "Jana", "Johanna", "Julia", "Karin", "Katharina", "Laura", "Lea", "Lena", "Lina", "Marie", "Martina",
+    "Mia", "Monika", "Nicole", "Petra", "Sabine", "Sandra", "Sarah", "Sophie", "Stefanie", "Susanne", "Tanja",
+    "Ursula", "Ute", "Yvonne", "Zoe", "Gisela", "Lotte",
+];
+
+static LAST: &[&str] = &[
+    "Bauer", "Becker", "Braun", "Fischer", "Frank", "Fuchs", "Günther", "Hartmann", "Hoffmann", "Huber",
+    "Jung", "Kaiser", "Keller", "Klein", "Koch", "Köhler", "König", "Krause", "Krüger", "Lang", "Lehmann",
+    "Maier", "Mayer", "Meier", "Meyer", "Müller", "Neumann", "Richter", "Roth", "Schäfer", "Schmid",
+    "Schmidt", "Schmitt", "Schneider", "Scholz", "Schulz", "Schwarz", "Schwarzenbach", "Wagner", "Weber",
+    "Weiß", "Werner", "Wolf", "Zimmermann",
+];
+
+static MALE_PREFIXES: &[&str] = &["Herr", "Herr Dr.", "Herr Prof. Dr."];
+static FEMALE_PREFIXES: &[&str] = &["Frau", "Frau Dr.", "Frau Prof. Dr."];
+
+static STREET_NAMES: &[&str] = &[
+    "Bahnhofstraße", "Hauptstraße", "Schulstraße", "Gartenstraße", "Dorfstraße", "Bergstraße", "Birkenweg",
+    "Lindenstraße", "Kirchstraße", "Waldstraße", "Ringstraße", "Schillerstraße", "Goethestraße",
+    "Mozartstraße", "Am Markt", "Rosenweg", "Wiesenweg", "Friedhofstraße", "Marktplatz", "Feldweg",
+];
+
+static SWISS_STREET_NAMES: &[&str] = &[
+    "Bahnhofstrasse", "Hauptstrasse", "Dorfstrasse", "Kirchgasse", "Seestrasse", "Schulhausstrasse",
+    "Bergstrasse", "Rosenweg", "Industriestrasse", "Lindenstrasse", "Gartenstrasse", "Poststrasse",
+    "Rue du Lac", "Via Cantonale", "Marktgasse",
+];
+
+static DE_CITIES: &[&str] = &[
+    "Berlin", "Hamburg", "München", "Köln", "Frankfurt am Main", "Stuttgart", "Düsseldorf", "Leipzig",
+    "Dortmund", "Essen", "Bremen", "Dresden", "Hannover", "Nürnberg", "Duisburg", "Bochum", "Wuppertal",
+    "Bielefeld", "Bonn", "Münster", "Mannheim", "Karlsruhe", "Augsburg", "Wiesbaden", "Freiburg im Breisgau",
+];
+
+static AT_CITIES: &[&str] = &[
+    "Wien", "Graz", "Linz", "Salzburg", "Innsbruck", "Klagenfurt", "Villach", "Wels", "St. Pölten",
+    "Dornbirn", "Wiener Neustadt", "Steyr", "Feldkirch", "Bregenz", "Leoben", "Eisenstadt",
+];
+
+static CH_CITIES: &[&str] = &[
+    "Zürich", "Genf", "Basel", "Lausanne", "Bern", "Winterthur", "Luzern", "St. Gallen", "Lugano", "Biel",
+    "Thun", "Köniz", "La Chaux-de-Fonds", "Freiburg", "Schaffhausen", "Chur", "Uster", "Sitten", "Zug",
+];
+
+static DE_STATES: &[(&str, &str)] = &[
+    ("Baden-Württemberg", "BW"),
+    ("Bayern", "BY"),
+    ("Berlin", "BE"),
+    ("Brandenburg", "BB"),
+    ("Bremen", "HB"),
+    ("Hamburg", "HH"),
+    ("Hessen", "HE"),
+    ("Mecklenburg-Vorpommern", "MV"),
+    ("Niedersachsen", "NI"),
+    ("Nordrhein-Westfalen", "NW"),
+    ("Rheinland-Pfalz", "RP"),
+    ("Saarland", "SL"),
+    ("Sachsen", "SN"),
+    ("Sachsen-Anhalt", "ST"),
+    ("Schleswig-Holstein", "SH"),
+    ("Thüringen", "TH"),
+];
+
+static AT_STATES: &[(&str, &str)] = &[
+    ("Burgenland", "B"),
+    ("Kärnten", "K"),
+    ("Niederösterreich", "NÖ"),
+    ("Oberösterreich", "OÖ"),
+    ("Salzburg", "S"),
+    ("Steiermark", "ST"),
+    ("Tirol", "T"),
+    ("Vorarlberg", "V"),
+    ("Wien", "W"),
+];
+
+static CH_CANTONS: &[(&str, &str)] = &[
+    ("Aargau", "AG"),
+    ("Appenzell Ausserrhoden", "AR"),
+    ("Appenzell Innerrhoden", "AI"),
+    ("Basel-Landschaft", "BL"),
+    ("Basel-Stadt", "BS"),
+    ("Bern", "BE"),
+    ("Freiburg", "FR"),
+    ("Genf", "GE"),
+    ("Glarus", "GL"),
+    ("Graubünden", "GR"),
+    ("Jura", "JU"),
+    ("Luzern", "LU"),
+    ("Neuenburg", "NE"),
+    ("Nidwalden", "NW"),
+    ("Obwalden", "OW"),
+    ("Schaffhausen", "SH"),
+    ("Schwyz", "SZ"),
+    ("Solothurn", "SO"),
+    ("St. Gallen", "SG"),
+    ("Tessin", "TI"),
+    ("Thurgau", "TG"),
+    ("Uri", "UR"),
+    ("Waadt", "VD"),
+    ("Wallis", "VS"),
+    ("Zug", "ZG"),
+    ("Zürich", "ZH"),
+];
+
+static DE_PHONES: &[&str] = &[
+    "030 #######",
+    "040 #######",
+    "089 #######",
+    "0221 #######",
+    "069 ########",
+    "0151 ########",
+    "0160 #######",
+    "0176 ########",
+    "+49 30 #######",
+    "+49 171 #######",
+];
+
+static AT_PHONES: &[&str] = &[
+    "01 #######",
+    "0316 ######",
+    "0732 ######",
+    "0662 ######",
+    "0664 #######",
+    "0676 #######",
+    "+43 1 #######",
+    "+43 664 #######",
+];
+
+static CH_PHONES: &[&str] = &[
+    "044 ### ## ##",
+    "031 ### ## ##",
+    "061 ### ## ##",
+    "022 ### ## ##",
+    "079 ### ## ##",
+    "076 ### ## ##",
+    "+41 44 ### ## ##",
+    "+41 79 ### ## ##",
+];
+
+static DE_SUFFIXES: &[&str] = &["GmbH", "AG", "GmbH & Co. KG", "KG", "OHG", "e.K.", "UG (haftungsbeschränkt)"];
+static AT_SUFFIXES: &[&str] = &["GmbH", "AG", "KG", "OG", "e.U."];
+static CH_SUFFIXES: &[&str] = &["AG", "GmbH", "Sàrl", "SA", "& Co."];
+
+static DE_DOMAINS: &[&str] = &["gmx.de", "web.de", "t-online.de", "posteo.de", "freenet.de", "example.de"];
+static AT_DOMAINS: &[&str] = &["gmx.at", "aon.at", "chello.at", "a1.net", "example.at"];
+static CH_DOMAINS: &[&str] = &["bluewin.ch", "gmx.ch", "sunrise.ch", "hispeed.ch", "example.ch"];
+
+// "Hauptstraße 12a": the number follows the name, occasionally with a letter
+fn street(name: &str, number: &str) -> String {
+    format!("{} {}", name, number)
+}
+
+fn street_number() -> String {
+    let number = rng::between(1, 199);
+    if rng::below(10) == 0 {
+        format!("{}{}", number, rng::pick(&['a', 'b', 'c']))
+    } else {
+        number.to_string()
+    }
+}
+
+fn swiss_street_number() -> String {
+    rng::between(1, 150).to_string()
+}
+
+fn de_postal_code() -> String {
+    format!("{:05}", rng::between(1067, 99998))
+}
+
+fn four_digit_postal_code() -> String {
+    rng::between(1000, 9999).to_string()
+}
+
+// "Schmidt & Weber GmbH" or "Schmidt GmbH"
+fn company(last: &str, other: &str, suffix: &str) -> String {
+    if rng::below(3) == 0 && last != other {
+        format!("{} & {} {}", last, other, suffix)
+    } else {
+        format!("{} {}", last, suffix)
+    }
+}
+
+// Steuerliche Identifikationsnummer: 11 digits, no leading zero
+fn de_national_id() -> String {
+    format!("{}{:010}", rng::between(1, 9), rng::between(0, 9_999_999_999))
+}
+
+// Sozialversicherungsnummer: 4 digits followed by the birth date as DDMMYY
+fn at_national_id() -> String {
+    format!(
+        "{:04} {:02}{:02}{:02}",
+        rng::between(1000, 9999),
+        rng::between(1, 28),
+        rng::between(1, 12),
+        rng::between(0, 99)
+    )
+}
+
+// AHV number, always starting with the country code 756
+fn ch_national_id() -> String {
+    format!("756.{:04}.{:04}.{:02}", rng::between(0, 9999), rng::between(0, 9999), rng::between(0, 99))
+}
+
+pub static DE_DE: Locale = Locale {
+    code: "de_DE",
+    male_first: MALE_FIRST,
+    female_first: FEMALE_FIRST,
+    last: LAST,
+    male_first_latin: &[],
+    female_first_latin: &[],
+    last_latin: &[],
+    family_name_first: false,
+    male_prefixes: MALE_PREFIXES,
+    female_prefixes: FEMALE_PREFIXES,
+    street_names: STREET_NAMES,
+    street_number,
+    street,
+    cities: DE_CITIES,
+    states: DE_STATES,
+    postal_code: de_postal_code,
+    country: ("Deutschland", "DE"),
+    phone_formats: DE_PHONES,
+    company_suffixes: DE_SUFFIXES,
+    company,
+    email_domains: DE_DOMAINS,
+    national_id: de_national_id,
+};
+
+pub static DE_AT: Locale = Locale {
+    code: "de_AT",
+    male_first: MALE_FIRST,
+    female_first: FEMALE_FIRST,
+    last: LAST,
+    male_first_latin: &[],
+    female_first_latin: &[],
+    last_latin: &[],
+    family_name_first: false,
+    male_prefixes: MALE_PREFIXES,
+    female_prefixes: FEMALE_PREFIXES,
+    street_names: STREET_NAMES,
+    street_number,
+    street,
+    cities: AT_CITIES,
+    states: AT_STATES,
+    postal_code: four_digit_postal_code,
+    country: ("Österreich", "AT"),
+    phone_formats: AT_PHONES,
+    company_suffixes: AT_SUFFIXES,
+    company,
+    email_domains: AT_DOMAINS,
+    national_id: at_national_id,
+};
+
+pub static DE_CH: Locale = Locale {
+    code: "de_CH",
+    male_first: MALE_FIRST,
+    female_first: FEMALE_FIRST,
+    last: LAST,
+    male_first_latin: &[],
+    female_first_latin: &[],
+    last_latin: &[],
+    family_name_first: false,
+    male_prefixes: MALE_PREFIXES,
+    female_prefixes: FEMALE_PREFIXES,
+    street_names: SWISS_STREET_NAMES,
+    street_number: swiss_street_number,
+    street,
+    cities: CH_CITIES,
+    states: CH_CANTONS,
+    postal_code: four_digit_postal_code,
+    country: ("Schweiz", "CH"),
+    phone_formats: CH_PHONES,
+    company_suffixes: CH_SUFFIXES,
+    company,
+    email_domains: CH_DOMAINS,
+    national_id: ch_national_id,
+};
@@ -0,0 +1,151 @@
+// France.
+
+use super::{rng, Locale};
+
+static MALE_FIRST: &[&str] = &[
+    "Adrien", "Alexandre", "Antoine", "Arthur", "Baptiste", "Benoît", "Bruno", "Camille", "Christophe", "Clément",
+    "Damien", "David", "Éric", "Étienne", "François", "Gabriel", "Guillaume", "Hugo", "Jacques", "Jean",
+    "Jérôme", "Julien", "Laurent", "Léo", "Louis", "Luc", "Lucas", "Mathieu", "Maxime", "Michel", "Nicolas",
+    "Olivier", "Patrick", "Philippe", "Pierre", "Raphaël", "Romain", "Sébastien", "Thomas", "Yves",
+];
+
+static FEMALE_FIRST: &[&str] = &[
+    "Agnès", "Alice", "Amélie", "Anaïs", "Anne", "Aurélie", "Brigitte", "Camille", "Caroline", "Catherine",
+    "Céline", "Chloé", "Claire", "Élise", "Émilie", "Emma", "Françoise", "Hélène", "Inès", "Isabelle", "Jade",
+    "Julie", "Juliette", "Léa", "Louise", "Lucie", "Manon", "Margaux", "Marie", "Mathilde", "Nathalie",
+    "Océane", "Pauline", "Sandrine", "Sophie", "Stéphanie", "Sylvie", "Valérie", "Véronique", "Zoé",
+];
+
+static LAST: &[&str] = &[
+    "Martin", "Bernard", "Thomas", "Petit", "Robert", "Richard", "Durand", "Dubois", "Moreau", "Laurent",
+    "Simon", "Michel", "Lefèvre", "Leroy", "Roux", "David", "Bertrand", "Morel", "Fournier", "Girard",
+    "Bonnet", "Dupont", "Lambert", "Fontaine", "Rousseau", "Vincent", "Muller", "Lefebvre", "Faure", "André",
+    "Mercier", "Blanc", "Guérin", "Boyer", "Garnier", "Chevalier", "François", "Legrand", "Gauthier", "Perrin",
+];
+
+static MALE_PREFIXES: &[&str] = &["M.", "Dr"];
+static FEMALE_PREFIXES: &[&str] = &["Mme", "Dr"];
+
+static STREET_NAMES: &[&str] = &[
+    "rue de la République", "rue Victor Hugo", "rue Jean Jaurès", "avenue de la Gare", "rue de l'Église",
+    "place de la Mairie", "rue Pasteur", "boulevard Gambetta", "rue du Moulin", "rue des Écoles",
+    "avenue du Général de Gaulle", "rue Nationale", "chemin des Vignes", "rue de Paris", "allée des Tilleuls",
+    "rue du Château", "impasse des Lilas", "quai de la Loire", "rue Voltaire", "avenue Foch",
+];
+
+static CITIES: &[&str] = &[
+    "Paris", "Marseille", "Lyon", "Toulouse", "Nice", "Nantes", "Montpellier", "Strasbourg", "Bordeaux",
+    "Lille", "Rennes", "Reims", "Toulon", "Saint-Étienne", "Le Havre", "Grenoble", "Dijon", "Angers", "Nîmes",
+    "Clermont-Ferrand", "Aix-en-Provence", "Brest", "Tours", "Amiens", "Limoges",
+];
+
+// Metropolitan régions with their ISO 3166-2 codes
+static REGIONS: &[(&str, &str)] = &[
+    ("Auvergne-Rhône-Alpes", "ARA"),
+    ("Bourgogne-Franche-Comté", "BFC"),
+    ("Bretagne", "BRE"),
+    ("Centre-Val de Loire", "CVL"),
+    ("Corse", "COR"),
+    ("Grand Est", "GES"),
+    ("Hauts-de-France", "HDF"),
+    ("Île-de-France", "IDF"),
+    ("Normandie", "NOR"),
+    ("Nouvelle-Aquitaine", "NAQ"),
+    ("Occitanie", "OCC"),
+    ("Pays de la Loire", "PDL"),
+    ("Provence-Alpes-Côte d'Azur", "PAC"),
+];
+
+static PHONES: &[&str] = &[
+    "01 ## ## ## ##",
+    "02 ## ## ## ##",
+    "03 ## ## ## ##",
+    "04 ## ## ## ##",
+    "05 ## ## ## ##",
+    "06 ## ## ## ##",
+    "07 ## ## ## ##",
+    "+33 1 ## ## ## ##",
+    "+33 6 ## ## ## ##",
+];
+
+static SUFFIXES: &[&str] = &["SA", "SARL", "SAS", "SASU", "EURL", "et Fils"];
+
+static DOMAINS: &[&str] = &["orange.fr", "free.fr", "laposte.net", "sfr.fr", "wanadoo.fr", "example.fr"];
+
+// "12 bis rue Victor Hugo": the number comes first
+fn street(name: &str, number: &str) -> String {
+    format!("{} {}", number, name)
+}
+
+fn street_number() -> String {
+    let number = rng::between(1, 150);
+    if rng::below(12) == 0 {
+        format!("{} {}", number, rng::pick(&["bis", "ter"]))
+    } else {
+        number.to_string()
+    }
+}
+
+// Département (01-95) followed by three digits
+fn postal_code() -> String {
+    format!("{:02}{:03}", rng::between(1, 95), rng::between(0, 999))
+}
+
+// "Dupont SARL" or "Martin et Fils"
+fn company(last: &str, other: &str, suffix: &str) -> String {
+    if rng::below(4) == 0 && last != other {
+        format!("{} & {} {}", last, other, suffix)
+    } else {
+        format!("{} {}", last, suffix)
+    }
+}
+
+// Numéro de sécurité sociale: sex, birth year and month, département, commune
+// and order number, followed by the key 97 - (number mod 97)
+fn national_id() -> String {
+    let number = format!(
+        "{}{:02}{:02}{:02}{:03}{:03}",
+        rng::between(1, 2),
+        rng::between(0, 99),
+        rng::between(1, 12),
+        rng::between(1, 95),
+        rng::between(1, 999),
+        rng::between(1, 999)
+    );
+    let key = 97 - number.parse::<u64>().unwrap_or(0) % 97;
+    format!(
+        "{} {} {} {} {} {} {:02}",
+        &number[..1],
+        &number[1..3],
+        &number[3..5],
+        &number[5..7],
+        &number[7..10],
+        &number[10..],
+        key
+    )
+}
+
+pub static FR_FR: Locale = Locale {
+    code: "fr_FR",
+    male_first: MALE_FIRST,
+    female_first: FEMALE_FIRST,
+    last: LAST,
+    male_first_latin: &[],
+    female_first_latin: &[],
+    last_latin: &[],
+    family_name_first: false,
+    male_prefixes: MALE_PREFIXES,
+    female_prefixes: FEMALE_PREFIXES,
+    street_names: STREET_NAMES,
+    street_number,
+    street,
+    cities: CITIES,
+    states: REGIONS,
+    postal_code,
+    country: ("France", "FR"),
+    phone_formats: PHONES,
+    company_suffixes: SUFFIXES,
+    company,
+    email_domains: DOMAINS,
+    national_id,
+};
@@ -0,0 +1,155 @@
+// Japan. Names are written in kanji, with Hepburn romanizations for email
+// addresses and usernames.
+
+use super::{rng, Locale};
+
+static MALE_FIRST: &[&str] = &[
+    "翔太", "大輔", "健太", "拓也", "直樹", "亮", "誠", "大樹", "翔", "蓮", "湊", "悠真", "陽翔", "樹", "健一",
+    "浩二", "和也", "達也", "哲也", "隆",
+];
+
+static MALE_FIRST_LATIN: &[&str] = &[
+    "Shota", "Daisuke", "Kenta", "Takuya", "Naoki", "Ryo", "Makoto", "Daiki", "Sho", "Ren", "Minato", "Yuma",
+    "Haruto", "Itsuki", "Kenichi", "Koji", "Kazuya", "Tatsuya", "Tetsuya", "Takashi",
+];
+
+static FEMALE_FIRST: &[&str] = &[
+    "陽菜", "結衣", "美咲", "さくら", "葵", "凛", "花子", "愛", "真由美", "由美子", "恵", "彩", "七海", "美優",
+    "優子", "裕子", "智子", "明美", "芽依", "結菜",
+];
+
+static FEMALE_FIRST_LATIN: &[&str] = &[
+    "Hina", "Yui", "Misaki", "Sakura", "Aoi", "Rin", "Hanako", "Ai", "Mayumi", "Yumiko", "Megumi", "Aya",
+    "Nanami", "Miyu", "Yuko", "Hiroko", "Tomoko", "Akemi", "Mei", "Yuna",
+];
+
+static LAST: &[&str] = &[
+    "佐藤", "鈴木", "高橋", "田中", "伊藤", "渡辺", "山本", "中村", "小林", "加藤", "吉田", "山田", "佐々木",
+    "山口", "松本", "井上", "木村", "林", "斎藤", "清水",
+];
+
+static LAST_LATIN: &[&str] = &[
+    "Sato", "Suzuki", "Takahashi", "Tanaka", "Ito", "Watanabe", "Yamamoto", "Nakamura", "Kobayashi", "Kato",
+    "Yoshida", "Yamada", "Sasaki", "Yamaguchi", "Matsumoto", "Inoue", "Kimura", "Hayashi", "Saito", "Shimizu",
+];
+
+// Neighbourhoods; the block numbers follow
+static TOWNS: &[&str] = &[
+    "本町", "中央", "栄町", "緑町", "旭町", "幸町", "東町", "西町", "南町", "北町", "新町", "桜木町", "元町",
+    "大手町", "錦町", "昭和町", "駅前", "城山", "若葉", "泉町",
+];
+
+static CITIES: &[&str] = &[
+    "東京都千代田区", "東京都新宿区", "東京都世田谷区", "横浜市", "大阪市", "名古屋市", "札幌市", "福岡市",
+    "神戸市", "川崎市", "京都市", "さいたま市", "広島市", "仙台市", "千葉市", "北九州市", "堺市", "新潟市",
+    "浜松市", "熊本市",
+];
+
+// Prefectures with their ISO 3166-2 codes
+static PREFECTURES: &[(&str, &str)] = &[
+    ("北海道", "JP-01"),
+    ("宮城県", "JP-04"),
+    ("茨城県", "JP-08"),
+    ("埼玉県", "JP-11"),
+    ("千葉県", "JP-12"),
+    ("東京都", "JP-13"),
+    ("神奈川県", "JP-14"),
+    ("新潟県", "JP-15"),
+    ("静岡県", "JP-22"),
+    ("愛知県", "JP-23"),
+    ("京都府", "JP-26"),
+    ("大阪府", "JP-27"),
+    ("兵庫県", "JP-28"),
+    ("奈良県", "JP-29"),
+    ("広島県", "JP-34"),
+    ("香川県", "JP-37"),
+    ("福岡県", "JP-40"),
+    ("熊本県", "JP-43"),
+    ("鹿児島県", "JP-46"),
+    ("沖縄県", "JP-47"),
+];
+
+static PHONES: &[&str] = &[
+    "03-####-####",
+    "06-####-####",
+    "045-###-####",
+    "052-###-####",
+    "092-###-####",
+    "011-###-####",
+    "090-####-####",
+    "080-####-####",
+    "070-####-####",
+    "+81 3-####-####",
+    "+81 90-####-####",
+];
+
+static SUFFIXES: &[&str] = &["株式会社", "有限会社", "合同会社"];
+
+// Business words placed between the family name and the company form
+static TRADES: &[&str] = &["商事", "工業", "建設", "製作所", "電機", "物産", "運輸", "食品"];
+
+static DOMAINS: &[&str] = &["yahoo.co.jp", "docomo.ne.jp", "ezweb.ne.jp", "nifty.com", "example.jp"];
+
+// "本町2-14-7": chōme, block and house number follow the neighbourhood
+fn street(name: &str, number: &str) -> String {
+    format!("{}{}", name, number)
+}
+
+fn street_number() -> String {
+    format!("{}-{}-{}", rng::between(1, 9), rng::between(1, 30), rng::between(1, 20))
+}
+
+fn postal_code() -> String {
+    format!("{:03}-{:04}", rng::between(1, 999), rng::between(0, 9999))
+}
+
+// The company form goes before ("株式会社佐藤") or after ("佐藤工業株式会社") the name
+fn company(last: &str, _other: &str, suffix: &str) -> String {
+    if rng::below(2) == 0 {
+        format!("{}{}", suffix, last)
+    } else {
+        format!("{}{}{}", last, rng::pick(TRADES), suffix)
+    }
+}
+
+// Individual number (My Number): 11 digits and a check digit
+fn national_id() -> String {
+    let digits: Vec<u64> = (0..11).map(|_| rng::below(10)).collect();
+    // Weights run 2..=7 twice from the rightmost digit
+    let sum: u64 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(n, d)| d * if n < 6 { n as u64 + 2 } else { n as u64 - 4 })
+        .sum();
+    let check = match sum % 11 {
+        0 | 1 => 0,
+        r => 11 - r,
+    };
+    digits.iter().chain(std::iter::once(&check)).map(|d| d.to_string()).collect()
+}
+
+pub static JA_JP: Locale = Locale {
+    code: "ja_JP",
+    male_first: MALE_FIRST,
+    female_first: FEMALE_FIRST,
+    last: LAST,
+    male_first_latin: MALE_FIRST_LATIN,
+    female_first_latin: FEMALE_FIRST_LATIN,
+    last_latin: LAST_LATIN,
+    family_name_first: true,
+    male_prefixes: &[],
+    female_prefixes: &[],
+    street_names: TOWNS,
+    street_number,
+    street,
+    cities: CITIES,
+    states: PREFECTURES,
+    postal_code,
+    country: ("日本", "JP"),
+    phone_formats: PHONES,
+    company_suffixes: SUFFIXES,
+    company,
+    email_domains: DOMAINS,
+    national_id,
+};
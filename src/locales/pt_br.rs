@@ -0,0 +1,147 @@
+// Brazil.
+
+use super::{rng, Locale};
+
+static MALE_FIRST: &[&str] = &[
+    "Miguel", "Arthur", "Gael", "Heitor", "Theo", "Davi", "Gabriel", "Bernardo", "Samuel", "João", "Pedro",
+    "Lucas", "Matheus", "Rafael", "Gustavo", "Felipe", "Bruno", "Thiago", "Rodrigo", "André", "Carlos",
+    "Eduardo", "Fernando", "Leonardo", "Marcelo", "Paulo", "Ricardo", "Vinícius", "José", "Antônio",
+];
+
+static FEMALE_FIRST: &[&str] = &[
+    "Helena", "Alice", "Laura", "Maria", "Valentina", "Heloísa", "Cecília", "Júlia", "Manuela", "Sophia",
+    "Ana", "Beatriz", "Camila", "Carolina", "Fernanda", "Gabriela", "Isabela", "Larissa", "Letícia",
+    "Luana", "Mariana", "Patrícia", "Rafaela", "Renata", "Sabrina", "Tatiane", "Vitória", "Yasmin",
+    "Bruna", "Aline",
+];
+
+static LAST: &[&str] = &[
+    "Silva", "Santos", "Oliveira", "Souza", "Rodrigues", "Ferreira", "Alves", "Pereira", "Lima", "Gomes",
+    "Costa", "Ribeiro", "Martins", "Carvalho", "Almeida", "Lopes", "Soares", "Fernandes", "Vieira", "Barbosa",
+    "Rocha", "Dias", "Nascimento", "Andrade", "Moreira", "Nunes", "Marques", "Machado", "Mendes", "Freitas",
+    "Cardoso", "Araújo", "Gonçalves", "Monteiro", "Teixeira", "Correia",
+];
+
+static MALE_PREFIXES: &[&str] = &["Sr.", "Dr."];
+static FEMALE_PREFIXES: &[&str] = &["Sra.", "Srta.", "Dra."];
+
+static STREET_NAMES: &[&str] = &[
+    "Rua das Flores", "Rua São José", "Avenida Brasil", "Rua Sete de Setembro", "Rua XV de Novembro",
+    "Avenida Paulista", "Rua Tiradentes", "Rua Santa Catarina", "Avenida Getúlio Vargas", "Rua da Consolação",
+    "Rua Dom Pedro II", "Travessa do Comércio", "Rua Rui Barbosa", "Avenida Presidente Vargas",
+    "Rua Marechal Deodoro", "Alameda Santos", "Rua Bahia", "Rua Amazonas", "Rua Primeiro de Maio",
+    "Avenida Atlântica",
+];
+
+static CITIES: &[&str] = &[
+    "São Paulo", "Rio de Janeiro", "Brasília", "Salvador", "Fortaleza", "Belo Horizonte", "Manaus", "Curitiba",
+    "Recife", "Goiânia", "Belém", "Porto Alegre", "Guarulhos", "Campinas", "São Luís", "Maceió", "Natal",
+    "Teresina", "Campo Grande", "João Pessoa", "Florianópolis", "Cuiabá", "Vitória", "Aracaju", "Londrina",
+];
+
+// The 26 states and the Federal District
+static STATES: &[(&str, &str)] = &[
+    ("Acre", "AC"),
+    ("Alagoas", "AL"),
+    ("Amapá", "AP"),
+    ("Amazonas", "AM"),
+    ("Bahia", "BA"),
+    ("Ceará", "CE"),
+    ("Distrito Federal", "DF"),
+    ("Espírito Santo", "ES"),
+    ("Goiás", "GO"),
+    ("Maranhão", "MA"),
+    ("Mato Grosso", "MT"),
+    ("Mato Grosso do Sul", "MS"),
+    ("Minas Gerais", "MG"),
+    ("Pará", "PA"),
+    ("Paraíba", "PB"),
+    ("Paraná", "PR"),
+    ("Pernambuco", "PE"),
+    ("Piauí", "PI"),
+    ("Rio de Janeiro", "RJ"),
+    ("Rio Grande do Norte", "RN"),
+    ("Rio Grande do Sul", "RS"),
+    ("Rondônia", "RO"),
+    ("Roraima", "RR"),
+    ("Santa Catarina", "SC"),
+    ("São Paulo", "SP"),
+    ("Sergipe", "SE"),
+    ("Tocantins", "TO"),
+];
+
+static PHONES: &[&str] = &[
+    "(11) 9####-####",
+    "(21) 9####-####",
+    "(31) 9####-####",
+    "(41) 9####-####",
+    "(51) 9####-####",
+    "(61) 9####-####",
+    "(11) 3###-####",
+    "(21) 2###-####",
+    "+55 11 9####-####",
+];
+
+static SUFFIXES: &[&str] = &["Ltda.", "S.A.", "ME", "EIRELI", "e Filhos"];
+
+static DOMAINS: &[&str] = &["uol.com.br", "bol.com.br", "terra.com.br", "ig.com.br", "globo.com", "example.com.br"];
+
+// "Rua das Flores, 123"
+fn street(name: &str, number: &str) -> String {
+    format!("{}, {}", name, number)
+}
+
+fn street_number() -> String {
+    rng::between(1, 3000).to_string()
+}
+
+// CEP
+fn postal_code() -> String {
+    format!("{:05}-{:03}", rng::between(1000, 99999), rng::between(0, 999))
+}
+
+// "Silva & Souza Ltda." or "Oliveira Ltda."
+fn company(last: &str, other: &str, suffix: &str) -> String {
+    if rng::below(3) == 0 && last != other {
+        format!("{} & {} {}", last, other, suffix)
+    } else {
+        format!("{} {}", last, suffix)
+    }
+}
+
+// CPF: nine digits and two mod-11 check digits
+fn national_id() -> String {
+    let mut digits: Vec<u64> = (0..9).map(|_| rng::below(10)).collect();
+    for _ in 0..2 {
+        let weight = digits.len() as u64 + 1;
+        let sum: u64 = digits.iter().enumerate().map(|(i, d)| d * (weight - i as u64)).sum();
+        digits.push(sum * 10 % 11 % 10);
+    }
+    let text: String = digits.iter().map(|d| d.to_string()).collect();
+    format!("{}.{}.{}-{}", &text[..3], &text[3..6], &text[6..9], &text[9..])
+}
+
+pub static PT_BR: Locale = Locale {
+    code: "pt_BR",
+    male_first: MALE_FIRST,
+    female_first: FEMALE_FIRST,
+    last: LAST,
+    male_first_latin: &[],
+    female_first_latin: &[],
+    last_latin: &[],
+    family_name_first: false,
+    male_prefixes: MALE_PREFIXES,
+    female_prefixes: FEMALE_PREFIXES,
+    street_names: STREET_NAMES,
+    street_number,
+    street,
+    cities: CITIES,
+    states: STATES,
+    postal_code,
+    country: ("Brasil", "BR"),
+    phone_formats: PHONES,
+    company_suffixes: SUFFIXES,
+    company,
+    email_domains: DOMAINS,
+    national_id,
+};
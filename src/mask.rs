@@ -0,0 +1,51 @@
+// Format-preserving masking, for fakeit_mask_like.
+//
+// Every uppercase letter, lowercase letter, uncased letter (such as CJK) and
+// digit is replaced with a random character of the same class from the same
+// 128-code-point block, and everything else is kept. Staying in the block
+// keeps ASCII ASCII, Cyrillic Cyrillic, and so on; since UTF-8 lengths only
+// change at multiples of 128, it also keeps the byte length.
+
+use super::rng;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Class {
+    Upper,
+    Lower,
+    Letter,
+    Number,
+}
+
+fn class(c: char) -> Option<Class> {
+    if c.is_uppercase() {
+        Some(Class::Upper)
+    } else if c.is_lowercase() {
+        Some(Class::Lower)
+    } else if c.is_alphabetic() {
+        Some(Class::Letter)
+    } else if c.is_numeric() {
+        Some(Class::Number)
+    } else {
+        None
+    }
+}
+
+pub fn mask(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match (c, class(c)) {
+            ('A'..='Z', _) => char::from(b'A' + rng::below(26) as u8),
+            ('a'..='z', _) => char::from(b'a' + rng::below(26) as u8),
+            ('0'..='9', _) => char::from(b'0' + rng::below(10) as u8),
+            (c, Some(kind)) => {
+                let start = c as u32 & !0x7f;
+                let candidates: Vec<char> = (start..start + 0x80)
+                    .filter_map(char::from_u32)
+                    .filter(|&candidate| class(candidate) == Some(kind))
+                    .collect();
+                rng::pick(&candidates)
+            }
+            (c, None) => c,
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+// Ranged numbers for fakeit_int, fakeit_decimal and fakeit_digits.
+//
+// All integer types are drawn through i128, which holds every INTEGER, BIGINT,
+// UBIGINT and HUGEINT value. fakeit_decimal returns DECIMAL(38,10): a scalar's
+// return type cannot depend on an argument, so the scale argument decides how
+// many of the ten decimals are used and the rest are zero.
+
+use libduckdb_sys as ffi;
+
+use super::rng;
+
+// Scale of the DECIMAL(38,10) values of fakeit_decimal
+pub const DECIMAL_SCALE: i64 = 10;
+
+pub fn from_hugeint(value: ffi::duckdb_hugeint) -> i128 {
+    ((value.upper as i128) << 64) | value.lower as i128
+}
+
+pub fn to_hugeint(value: i128) -> ffi::duckdb_hugeint {
+    ffi::duckdb_hugeint {
+        lower: value as u64,
+        upper: (value >> 64) as i64,
+    }
+}
+
+// Uniform integer in [min, max]
+pub fn int(min: i128, max: i128) -> Result<i128, String> {
+    if min > max {
+        return Err("min must not be greater than max".to_string());
+    }
+    // Ranges that fit in BIGINT take the 64-bit path
+    if min >= i64::MIN as i128 && max <= i64::MAX as i128 {
+        Ok(rng::between(min as i64, max as i64) as i128)
+    } else {
+        Ok(rng::between_i128(min, max))
+    }
+}
+
+// Uniform multiple of 10^-scale in [min, max]; min, max and the result are
+// DECIMAL(38,10) values, i.e. counts of 10^-10
+pub fn decimal(min: i128, max: i128, scale: i64) -> Result<i128, String> {
+    if !(0..=DECIMAL_SCALE).contains(&scale) {
+        return Err(format!("scale must be between 0 and {}, got {}", DECIMAL_SCALE, scale));
+    }
+    if min > max {
+        return Err("min must not be greater than max".to_string());
+    }
+    let step = 10i128.pow((DECIMAL_SCALE - scale) as u32);
+    let low = min.div_euclid(step) + (min.rem_euclid(step) != 0) as i128;
+    let high = max.div_euclid(step);
+    if low > high {
+        return Err(format!("no value with scale {} lies between min and max", scale));
+    }
+    Ok(rng::between_i128(low, high) * step)
+}
+
+pub const DIGITS_MAX: i64 = 10_000;
+
+// `n` random digits; leading zeros included
+pub fn digits(n: i64) -> Result<String, String> {
+    if !(0..=DIGITS_MAX).contains(&n) {
+        return Err(format!("n must be between 0 and {}, got {}", DIGITS_MAX, n));
+    }
+    Ok((0..n).map(|_| char::from(b'0' + rng::below(10) as u8)).collect())
+}
@@ -0,0 +1,50 @@
+// Fill-in patterns for formatted values such as phone numbers and postal codes,
+// with the placeholders of Python Faker: '#' is any digit, '%' a digit other
+// than 0 and '?' a letter. Each placeholder draws from an alphabet, which the
+// SQL functions let callers replace.
+
+use super::rng;
+
+pub const DIGITS: &str = "0123456789";
+pub const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Replace every '#' with a random digit
+pub fn numerify(pattern: &str) -> String {
+    numerify_with(pattern, &DIGITS.chars().collect::<Vec<_>>())
+}
+
+// Replace '#' and '%' with characters of `digits`
+pub fn numerify_with(pattern: &str, digits: &[char]) -> String {
+    fill(pattern, Some(digits), None)
+}
+
+// Replace '?' with characters of `letters`
+pub fn lexify(pattern: &str, letters: &[char]) -> String {
+    fill(pattern, None, Some(letters))
+}
+
+// Replace '#' and '%' with digits and '?' with characters of `letters`
+pub fn bothify(pattern: &str, letters: &[char]) -> String {
+    fill(pattern, Some(&DIGITS.chars().collect::<Vec<_>>()), Some(letters))
+}
+
+fn fill(pattern: &str, digits: Option<&[char]>, letters: Option<&[char]>) -> String {
+    // '%' avoids '0' unless the alphabet has nothing else
+    let non_zero: Option<Vec<char>> = digits.map(|d| {
+        let rest: Vec<char> = d.iter().copied().filter(|&c| c != '0').collect();
+        if rest.is_empty() {
+            d.to_vec()
+        } else {
+            rest
+        }
+    });
+    pattern
+        .chars()
+        .map(|c| match (c, digits, &non_zero, letters) {
+            ('#', Some(digits), _, _) => rng::pick(digits),
+            ('%', _, Some(non_zero), _) => rng::pick(non_zero),
+            ('?', _, _, Some(letters)) => rng::pick(letters),
+            _ => c,
+        })
+        .collect()
+}
@@ -0,0 +1,241 @@
+// The fakeit_persons table function: people whose attributes agree.
+//
+//   FROM fakeit_persons(1000)
+//
+// Each row starts from one identity (gender, first and last name, birthdate);
+// prefix, username and email are then derived from it, so that Jane Doe born
+// in 1987 is "Ms." with an address like jane.doe87@gmail.com instead of an
+// unrelated name from an independent generator.
+
+use duckdb::core::{DataChunkHandle, Inserter, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab};
+use libduckdb_sys as ffi;
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::functions::{self, Parameters, Type};
+use super::{locale, rng};
+
+// fakeit's own first names carry no gender, so the identity picks from these
+static MALE_FIRST: &[&str] = &[
+    "James", "John", "Robert", "Michael", "William", "David", "Richard", "Joseph", "Thomas", "Charles",
+    "Christopher", "Daniel", "Matthew", "Anthony", "Mark", "Donald", "Steven", "Paul", "Andrew", "Joshua",
+    "Kenneth", "Kevin", "Brian", "George", "Timothy", "Ronald", "Edward", "Jason", "Jeffrey", "Ryan",
+    "Jacob", "Gary", "Nicholas", "Eric", "Jonathan", "Stephen", "Larry", "Justin", "Scott", "Brandon",
+    "Benjamin", "Samuel", "Gregory", "Alexander", "Frank", "Patrick", "Raymond", "Jack", "Dennis", "Jerry",
+    "Tyler", "Aaron", "Jose", "Adam", "Nathan", "Henry", "Douglas", "Zachary", "Peter", "Kyle",
+];
+
+static FEMALE_FIRST: &[&str] = &[
+    "Mary", "Patricia", "Jennifer", "Linda", "Elizabeth", "Barbara", "Susan", "Jessica", "Sarah", "Karen",
+    "Lisa", "Nancy", "Betty", "Margaret", "Sandra", "Ashley", "Kimberly", "Emily", "Donna", "Michelle",
+    "Carol", "Amanda", "Dorothy", "Melissa", "Deborah", "Stephanie", "Rebecca", "Sharon", "Laura", "Cynthia",
+    "Kathleen", "Amy", "Angela", "Shirley", "Anna", "Brenda", "Pamela", "Emma", "Nicole", "Helen",
+    "Samantha", "Katherine", "Christine", "Debra", "Rachel", "Carolyn", "Janet", "Catherine", "Maria", "Heather",
+    "Diane", "Ruth", "Julie", "Olivia", "Joyce", "Virginia", "Victoria", "Kelly", "Lauren", "Christina",
+];
+
+static EMAIL_DOMAINS: &[&str] = &[
+    "gmail.com", "yahoo.com", "outlook.com", "hotmail.com", "icloud.com", "aol.com", "proton.me", "mail.com",
+];
+
+// Birthdates fall between 1935-01-01 and 2006-12-31, as days since 1970-01-01.
+// The range is fixed rather than relative to today so that seeded output
+// does not drift over time.
+const BIRTHDATE_MIN: i64 = -12784;
+const BIRTHDATE_MAX: i64 = 13513;
+
+// Every column is VARCHAR except the trailing birthdate, which is a DATE
+const COLUMNS: &[&str] = &[
+    "first_name",
+    "last_name",
+    "full_name",
+    "gender",
+    "prefix",
+    "username",
+    "email",
+    "birthdate",
+];
+
+struct Person {
+    first_name: &'static str,
+    last_name: &'static str,
+    full_name: String,
+    gender: &'static str,
+    prefix: &'static str,
+    username: String,
+    email: String,
+    // Days since 1970-01-01
+    birthdate: i32,
+}
+
+impl Person {
+    fn generate() -> Person {
+        let female = rng::below(2) == 0;
+        let gender = if female { "female" } else { "male" };
+        let birthdate = rng::between(BIRTHDATE_MIN, BIRTHDATE_MAX) as i32;
+
+        // Outside the default locale the names, prefix and email domains come
+        // from the locale's dictionary; username and email use its ASCII spelling
+        let (first_name, last_name, prefix, first, last, domains) = match locale::current() {
+            Some(l) => {
+                let first_name = l.first_name(female);
+                let last_name = l.last_name();
+                let prefix = l.prefix(female);
+                (first_name.text, last_name.text, prefix, first_name.ascii, last_name.ascii, l.email_domains)
+            }
+            None => {
+                let first_name = if female { rng::pick(FEMALE_FIRST) } else { rng::pick(MALE_FIRST) };
+                let last_name = rng::pick(fakeit::data::person::LAST);
+                let prefix = if rng::below(20) == 0 {
+                    "Dr."
+                } else if female {
+                    rng::pick(&["Ms.", "Mrs.", "Miss"])
+                } else {
+                    "Mr."
+                };
+                (first_name, last_name, prefix, first_name.to_lowercase(), last_name.to_lowercase(), EMAIL_DOMAINS)
+            }
+        };
+        let full_name = match locale::current() {
+            Some(l) => l.full_name(first_name, last_name),
+            None => format!("{} {}", first_name, last_name),
+        };
+
+        let initial = &first[..1];
+        let year = format!("{:02}", year_of(birthdate) % 100);
+
+        let username = match rng::below(4) {
+            0 => format!("{}{}", initial, last),
+            1 => format!("{}_{}", first, last),
+            2 => format!("{}{}{}", first, last, year),
+            _ => format!("{}{}{}", last, initial, year),
+        };
+        let local = match rng::below(4) {
+            0 => format!("{}.{}", first, last),
+            1 => format!("{}.{}{}", first, last, year),
+            2 => format!("{}{}", initial, last),
+            _ => username.clone(),
+        };
+        let email = format!("{}@{}", local, rng::pick(domains));
+
+        Person {
+            first_name,
+            last_name,
+            full_name,
+            gender,
+            prefix,
+            username,
+            email,
+            birthdate,
+        }
+    }
+
+    fn write(&self, output: &DataChunkHandle, col: usize, index: usize, row: usize) {
+        let mut vector = output.flat_vector(col);
+        let text = match index {
+            0 => self.first_name,
+            1 => self.last_name,
+            2 => self.full_name.as_str(),
+            3 => self.gender,
+            4 => self.prefix,
+            5 => self.username.as_str(),
+            6 => self.email.as_str(),
+            _ => {
+                vector.as_mut_slice::<i32>()[row] = self.birthdate;
+                return;
+            }
+        };
+        vector.insert(row, text);
+    }
+}
+
+// Calendar year of a day count since 1970-01-01 (proleptic Gregorian)
+fn year_of(days: i32) -> i64 {
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    yoe + era * 400 + if mp >= 10 { 1 } else { 0 }
+}
+
+pub struct PersonsBindData {
+    rows: u64,
+}
+
+pub struct PersonsInitData {
+    next_row: AtomicU64,
+    // Bind column index of every output column, in output order
+    projection: Vec<usize>,
+}
+
+pub struct PersonsTable;
+
+impl Parameters for PersonsTable {
+    const PARAMETERS: &'static [Type] = &[Type::Bigint];
+}
+
+impl VTab for PersonsTable {
+    type InitData = PersonsInitData;
+    type BindData = PersonsBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn Error>> {
+        let rows = bind.get_parameter(0).to_int64();
+        if rows < 0 {
+            return Err(format!("fakeit_persons: the number of rows must not be negative, got {}", rows).into());
+        }
+        let (birthdate, text) = COLUMNS.split_last().unwrap();
+        for name in text {
+            bind.add_result_column(name, LogicalTypeHandle::from(LogicalTypeId::Varchar));
+        }
+        bind.add_result_column(birthdate, LogicalTypeHandle::from(LogicalTypeId::Date));
+        bind.set_cardinality(rows as u64, true);
+        Ok(PersonsBindData { rows: rows as u64 })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn Error>> {
+        let projection = init.get_column_indices().into_iter().map(|i| i as usize).collect();
+        Ok(PersonsInitData {
+            next_row: AtomicU64::new(0),
+            projection,
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn Error>> {
+        let bind_data = func.get_bind_data();
+        let init_data = func.get_init_data();
+
+        let chunk_size = unsafe { ffi::duckdb_vector_size() };
+        let start = init_data.next_row.fetch_add(chunk_size, Ordering::Relaxed);
+        if start >= bind_data.rows {
+            output.set_len(0);
+            return Ok(());
+        }
+        let len = (bind_data.rows - start).min(chunk_size) as usize;
+
+        let _stream = rng::lock();
+        for row in 0..len {
+            // The whole identity is drawn even when only some columns are read,
+            // so a projection sees the same people as a full scan
+            let person = Person::generate();
+            for (col, &index) in init_data.projection.iter().enumerate() {
+                // Row-id and other virtual columns are never read
+                if index < COLUMNS.len() {
+                    person.write(output, col, index, row);
+                }
+            }
+        }
+        output.set_len(len);
+        Ok(())
+    }
+
+    fn supports_pushdown() -> bool {
+        true
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        functions::parameters::<Self>()
+    }
+}
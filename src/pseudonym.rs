@@ -0,0 +1,37 @@
+// Deterministic pseudonyms of real values, for fakeit_pseudonymize.
+//
+// A value is encoded like the key of a keyed overload (see key.rs) and signed
+// with HMAC-SHA256 under the caller's secret, together with the generator
+// name. The MAC seeds the generator, so equal values get equal pseudonyms
+// wherever the secret is the same, and nobody without the secret can tell
+// which real value a pseudonym came from by trying candidates.
+
+use super::registry;
+use super::rng;
+
+// The pseudonym of the encoded value `value` drawn from `generator`
+pub fn pseudonymize(value: &[u8], generator: &str, secret: &str) -> Result<String, String> {
+    let function = registry::function(generator)
+        .filter(|function| function.generator.is_some())
+        .ok_or_else(|| format!("unknown generator '{}'", generator))?;
+    // The name of the function keeps the pseudonyms of one value under
+    // different generators independent, and the same under every spelling of
+    // one generator ("contact_email", "fakeit_contact_email", "contact.email")
+    let name = function.name.strip_prefix("fakeit_").unwrap_or(function.name);
+    let seed = keyed_seed(secret, name, value)?;
+    Ok(rng::with_key(seed, || function.generator.unwrap().to_text()))
+}
+
+// A stream seed from the HMAC of `value` under `secret`, with `context`
+// telling apart the different uses of one value
+pub fn keyed_seed(secret: &str, context: &str, value: &[u8]) -> Result<u64, String> {
+    if secret.is_empty() {
+        return Err("the secret must not be empty".to_string());
+    }
+    let mut message = Vec::with_capacity(context.len() + 1 + value.len());
+    message.extend_from_slice(context.as_bytes());
+    message.push(0);
+    message.extend_from_slice(value);
+    let mac = hmac_sha256::HMAC::mac(message, secret.as_bytes());
+    Ok(u64::from_le_bytes(mac[..8].try_into().unwrap()))
+}
@@ -0,0 +1,146 @@
+// Strings that match a regular expression, for fakeit_regex.
+//
+// The pattern is parsed with regex-syntax and compiled into a small tree that
+// is walked once per generated string. Classes are narrowed to printable ASCII
+// whenever that leaves something to pick from, so `\d`, `\w` and `.` give the
+// characters people expect rather than arbitrary Unicode. Anchors and word
+// boundaries generate nothing, and unbounded repetitions (`*`, `+`, `{n,}`)
+// stop at MAX_EXTRA repetitions beyond their minimum. Patterns that could
+// generate strings longer than MAX_LENGTH characters, such as `a{1000000}` or
+// `(a{1000}){1000}`, are rejected.
+
+use regex_syntax::hir::{Class, ClassUnicode, ClassUnicodeRange, Hir, HirKind};
+
+use super::rng;
+
+const MAX_EXTRA: u32 = 10;
+const MAX_LENGTH: u64 = 10_000;
+
+enum Node {
+    Literal(String),
+    // Inclusive character ranges and the number of characters they hold
+    Class(Vec<(u32, u32)>, u64),
+    Repeat(Box<Node>, u32, u32),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+}
+
+pub struct Pattern {
+    source: String,
+    root: Node,
+}
+
+impl Pattern {
+    pub fn parse(source: &str) -> Result<Pattern, String> {
+        let hir = regex_syntax::Parser::new().parse(source).map_err(|e| {
+            // The message ends with the reason after a copy of the pattern
+            let message = e.to_string();
+            let reason = message.lines().last().unwrap_or_default().trim_start_matches("error: ");
+            format!("invalid pattern '{}': {}", source, reason)
+        })?;
+        let root = compile(&hir).ok_or_else(|| format!("pattern '{}' can never match", source))?;
+        if max_length(&root) > MAX_LENGTH {
+            return Err(format!("pattern '{}' can generate strings longer than {} characters", source, MAX_LENGTH));
+        }
+        Ok(Pattern {
+            source: source.to_string(),
+            root,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+        render(&self.root, &mut out);
+        out
+    }
+}
+
+// None for an expression that matches nothing, such as an empty class
+fn compile(hir: &Hir) -> Option<Node> {
+    Some(match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => Node::Concat(Vec::new()),
+        HirKind::Literal(literal) => Node::Literal(String::from_utf8_lossy(&literal.0).into_owned()),
+        HirKind::Class(Class::Unicode(class)) => class_node(class)?,
+        // Byte classes only come from (?-u) and stay within ASCII
+        HirKind::Class(Class::Bytes(class)) => class_node(&ClassUnicode::new(
+            class.ranges().iter().map(|r| ClassUnicodeRange::new(r.start() as char, r.end() as char)),
+        ))?,
+        HirKind::Repetition(repetition) => {
+            let max = repetition.max.unwrap_or(repetition.min.saturating_add(MAX_EXTRA));
+            match compile(&repetition.sub) {
+                // Repeating what generates nothing would only spin
+                Some(sub) if max_length(&sub) == 0 => sub,
+                Some(sub) => Node::Repeat(Box::new(sub), repetition.min, max),
+                // A repetition of nothing can still match zero times
+                None if repetition.min == 0 => Node::Concat(Vec::new()),
+                None => return None,
+            }
+        }
+        HirKind::Capture(capture) => compile(&capture.sub)?,
+        HirKind::Concat(subs) => Node::Concat(subs.iter().map(compile).collect::<Option<_>>()?),
+        HirKind::Alternation(subs) => {
+            let branches: Vec<Node> = subs.iter().filter_map(compile).collect();
+            if branches.is_empty() {
+                return None;
+            }
+            Node::Alternation(branches)
+        }
+    })
+}
+
+// The length in characters of the longest string `node` generates
+fn max_length(node: &Node) -> u64 {
+    match node {
+        Node::Literal(text) => text.chars().count() as u64,
+        Node::Class(..) => 1,
+        Node::Repeat(sub, _, max) => max_length(sub).saturating_mul(*max as u64),
+        Node::Concat(subs) => subs.iter().map(max_length).fold(0, u64::saturating_add),
+        Node::Alternation(branches) => branches.iter().map(max_length).max().unwrap_or(0),
+    }
+}
+
+fn class_node(class: &ClassUnicode) -> Option<Node> {
+    let mut printable = class.clone();
+    printable.intersect(&ClassUnicode::new([ClassUnicodeRange::new(' ', '~')]));
+    let chosen = if printable.ranges().is_empty() { class } else { &printable };
+    let ranges: Vec<(u32, u32)> = chosen.ranges().iter().map(|r| (r.start() as u32, r.end() as u32)).collect();
+    let size: u64 = ranges.iter().map(|&(start, end)| (end - start + 1) as u64).sum();
+    (size > 0).then_some(Node::Class(ranges, size))
+}
+
+fn render(node: &Node, out: &mut String) {
+    match node {
+        Node::Literal(text) => out.push_str(text),
+        Node::Class(ranges, size) => out.push(pick_char(ranges, *size)),
+        Node::Repeat(sub, min, max) => {
+            let count = rng::between(*min as i64, *max as i64);
+            for _ in 0..count {
+                render(sub, out);
+            }
+        }
+        Node::Concat(subs) => subs.iter().for_each(|sub| render(sub, out)),
+        Node::Alternation(branches) => render(&branches[rng::below(branches.len() as u64) as usize], out),
+    }
+}
+
+fn pick_char(ranges: &[(u32, u32)], size: u64) -> char {
+    loop {
+        let mut index = rng::below(size);
+        for &(start, end) in ranges {
+            let len = (end - start + 1) as u64;
+            if index < len {
+                // Ranges spanning the surrogate gap hold code points that are
+                // not characters; draw again when one comes up
+                if let Some(c) = char::from_u32(start + index as u32) {
+                    return c;
+                }
+                break;
+            }
+            index -= len;
+        }
+    }
+}
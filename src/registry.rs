@@ -0,0 +1,58 @@
+// Generators addressable by name, e.g. "name_full" for fakeit_name_full().
+// Used wherever a generator is picked from a string rather than a function call.
+// The names are those of the functions in FUNCTIONS (lib.rs) that declare a
+// generator.
+
+use duckdb::core::{LogicalTypeHandle, LogicalTypeId};
+
+use super::functions::Function;
+
+#[derive(Clone, Copy)]
+pub enum Generator {
+    Varchar(fn() -> String),
+    Bigint(fn() -> i64),
+    Double(fn() -> f64),
+    Boolean(fn() -> bool),
+    // DECIMAL(18,2), as a count of hundredths
+    Cents(fn() -> i64),
+    // Big-endian UUID bits, see uuid.rs
+    Uuid(fn() -> u128),
+}
+
+impl Generator {
+    pub fn logical_type(self) -> LogicalTypeHandle {
+        match self {
+            Generator::Varchar(_) => LogicalTypeHandle::from(LogicalTypeId::Varchar),
+            Generator::Bigint(_) => LogicalTypeHandle::from(LogicalTypeId::Bigint),
+            Generator::Double(_) => LogicalTypeHandle::from(LogicalTypeId::Double),
+            Generator::Boolean(_) => LogicalTypeHandle::from(LogicalTypeId::Boolean),
+            Generator::Cents(_) => LogicalTypeHandle::decimal(18, 2),
+            Generator::Uuid(_) => LogicalTypeHandle::from(LogicalTypeId::Uuid),
+        }
+    }
+
+    // One value rendered as text, the way DuckDB would cast it to VARCHAR
+    pub fn to_text(self) -> String {
+        match self {
+            Generator::Varchar(f) => f(),
+            Generator::Bigint(f) => f().to_string(),
+            Generator::Double(f) => super::text::double(f()),
+            Generator::Boolean(f) => f().to_string(),
+            Generator::Cents(f) => super::text::decimal(f() as i128, 2),
+            Generator::Uuid(f) => super::uuid::to_text(f()),
+        }
+    }
+}
+
+// The function a generator name refers to; accepts "name_full",
+// "fakeit_name_full" and "name.full"
+pub fn function(name: &str) -> Option<&'static Function> {
+    let name = name.strip_prefix("fakeit_").unwrap_or(name).replace('.', "_");
+    super::FUNCTIONS
+        .iter()
+        .find(|function| function.name.strip_prefix("fakeit_") == Some(name.as_str()))
+}
+
+pub fn lookup(name: &str) -> Option<Generator> {
+    function(name).and_then(|function| function.generator)
+}
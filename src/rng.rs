@@ -0,0 +1,95 @@
+// Shared access to the random stream behind every generator.
+//
+// The fakeit crate draws all of its randomness from the process-wide
+// `simplerand` generator. Scalars hold `lock()` for a whole chunk so that a
+// seeded stream is consumed in row order instead of being interleaved with
+// other generators running on other threads.
+
+use std::sync::{Mutex, MutexGuard};
+
+use super::locale;
+
+static STREAM: Mutex<()> = Mutex::new(());
+
+pub fn lock() -> MutexGuard<'static, ()> {
+    // A panic while generating leaves nothing to repair, so recover the guard
+    STREAM.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// Reseed the global stream; the seed is mixed first so that nearby seeds
+// (1, 2, 3, ...) do not start out on correlated sequences
+pub fn set_seed(seed: i64) {
+    simplerand::set_seed::<u64>(mix(seed as u64) as u128);
+}
+
+// SplitMix64 finalizer
+pub fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+// 63 random bits from the shared stream. The stream is an LCG whose low bits
+// have short periods, so the helpers below only ever use the high bits.
+pub fn next() -> u64 {
+    simplerand::rand::<u64>()
+}
+
+// Uniform integer in [0, n)
+pub fn below(n: u64) -> u64 {
+    ((next() as u128 * n as u128) >> 63) as u64
+}
+
+// Uniform integer in [min, max]. One draw has only 2^63 values, so wider
+// spans go through between_i128 rather than skip values.
+pub fn between(min: i64, max: i64) -> i64 {
+    let span = (max as i128 - min as i128 + 1) as u128;
+    if span > 1 << 63 {
+        return between_i128(min as i128, max as i128) as i64;
+    }
+    (min as i128 + ((next() as u128 * span) >> 63) as i128) as i64
+}
+
+// 128 random bits, built from the high bits of three draws
+pub fn bits128() -> u128 {
+    ((next() as u128) << 65) | ((next() as u128) << 2) | (next() >> 61) as u128
+}
+
+// Uniform integer in [min, max] for ranges wider than 64 bits
+pub fn between_i128(min: i128, max: i128) -> i128 {
+    let span = (max as u128).wrapping_sub(min as u128).wrapping_add(1);
+    if span == 0 {
+        // The whole i128 range
+        return bits128() as i128;
+    }
+    // Reject the top partial copy of the span so that every value is equally likely
+    let limit = u128::MAX - u128::MAX % span;
+    loop {
+        let bits = bits128();
+        if bits < limit {
+            return (min as u128).wrapping_add(bits % span) as i128;
+        }
+    }
+}
+
+// Uniform float in [0, 1), from the top 53 bits
+pub fn unit() -> f64 {
+    (next() >> 10) as f64 / (1u64 << 53) as f64
+}
+
+// Uniform element of a non-empty slice
+pub fn pick<T: Copy>(items: &[T]) -> T {
+    items[below(items.len() as u64) as usize]
+}
+
+// Run `f` on a stream seeded from `key`, then resume the global stream where it
+// left off. The caller must hold `lock()`. `f` sees the default locale unless
+// the caller picked one, so the process-wide locale cannot change the result.
+pub fn with_key<T>(key: u64, f: impl FnOnce() -> T) -> T {
+    let saved = simplerand::seed::<u64>();
+    simplerand::set_seed::<u64>(mix(key) as u128);
+    let result = locale::pinned(f);
+    simplerand::set_seed::<u64>(saved);
+    result
+}
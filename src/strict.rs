@@ -0,0 +1,99 @@
+// Scalar functions that pass DECIMAL values through, such as fakeit_nullable
+// and fakeit_choice.
+//
+// DuckDB does not tell decimal types apart when it picks an overload, so these
+// functions have a single DECIMAL(18,2) overload and DuckDB would cast every
+// other decimal to it, rounding the values or failing on large ones. They are
+// registered through the C API with a bind callback that rejects a decimal
+// argument, or a list of decimals, unless a decimal parameter of the function
+// holds all of its values; invoke then hands the chunk to the function's own
+// VScalar::invoke.
+
+use duckdb::core::{DataChunkHandle, FlatVector, LogicalTypeHandle};
+use libduckdb_sys as ffi;
+
+use super::functions::{self, c_string, Function, Type};
+use super::registry;
+
+pub unsafe fn register(con: ffi::duckdb_connection, name: &str) -> duckdb::Result<()> {
+    let function = registry::function(name).unwrap();
+    let extra_info = function as *const Function as *const _;
+    functions::register_with_bind(con, name, function.overloads, bind, invoke, extra_info)
+}
+
+unsafe extern "C" fn bind(info: ffi::duckdb_bind_info) {
+    let function = &*(ffi::duckdb_scalar_function_bind_get_extra_info(info) as *const Function);
+    for i in 0..ffi::duckdb_scalar_function_bind_get_argument_count(info) {
+        let mut argument = ffi::duckdb_scalar_function_bind_get_argument(info, i);
+        let mut t = ffi::duckdb_expression_return_type(argument);
+        let checked = check(function, i as usize, t);
+        ffi::duckdb_destroy_logical_type(&mut t);
+        ffi::duckdb_destroy_expression(&mut argument);
+        if let Err(message) = checked {
+            ffi::duckdb_scalar_function_bind_set_error(info, c_string(&message).as_ptr());
+            return;
+        }
+    }
+}
+
+// Whether the decimals in argument `i`, of type `t`, fit a decimal parameter
+// of `function` in that place without losing digits. Where no overload takes
+// a decimal, as for a probability or weights, they go to DOUBLE instead.
+unsafe fn check(function: &Function, i: usize, t: ffi::duckdb_logical_type) -> Result<(), String> {
+    match ffi::duckdb_get_type_id(t) {
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_DECIMAL => {
+            let (width, scale) = (ffi::duckdb_decimal_width(t), ffi::duckdb_decimal_scale(t));
+            let decimals: Vec<(u8, u8)> = function
+                .overloads
+                .iter()
+                .filter_map(|(params, _)| params.get(i).and_then(|&param| decimal(param)))
+                .collect();
+            if decimals.is_empty()
+                || decimals.iter().any(|&(to_width, to_scale)| scale <= to_scale && width - scale <= to_width - to_scale)
+            {
+                return Ok(());
+            }
+            let fits: Vec<String> = decimals.iter().map(|&(width, scale)| Type::Decimal(width, scale).name()).collect();
+            Err(format!(
+                "{}: DECIMAL({},{}) values would be rounded to {}; cast them to DOUBLE or VARCHAR",
+                function.name,
+                width,
+                scale,
+                fits.join(" or ")
+            ))
+        }
+        ffi::DUCKDB_TYPE_DUCKDB_TYPE_LIST => {
+            let mut child = ffi::duckdb_list_type_child_type(t);
+            let checked = check(function, i, child);
+            ffi::duckdb_destroy_logical_type(&mut child);
+            checked
+        }
+        _ => Ok(()),
+    }
+}
+
+// The decimal type of a parameter, or of the elements of a list parameter
+fn decimal(t: Type) -> Option<(u8, u8)> {
+    match t {
+        Type::Decimal(width, scale) => Some((width, scale)),
+        Type::List(child) => decimal(*child),
+        _ => None,
+    }
+}
+
+unsafe extern "C" fn invoke(info: ffi::duckdb_function_info, input: ffi::duckdb_data_chunk, output: ffi::duckdb_vector) {
+    let function = &*(ffi::duckdb_scalar_function_get_extra_info(info) as *const Function);
+    let vectors: Vec<_> = (0..ffi::duckdb_data_chunk_get_column_count(input))
+        .map(|col| ffi::duckdb_data_chunk_get_vector(input, col))
+        .collect();
+    let types: Vec<LogicalTypeHandle> = vectors.iter().map(|&vector| FlatVector::from(vector).logical_type()).collect();
+    let mut chunk = DataChunkHandle::new(&types);
+    for (col, &vector) in vectors.iter().enumerate() {
+        ffi::duckdb_vector_reference_vector(ffi::duckdb_data_chunk_get_vector(chunk.get_ptr(), col as u64), vector);
+    }
+    chunk.set_len(ffi::duckdb_data_chunk_get_size(input) as usize);
+    let mut output = output;
+    if let Err(e) = (function.invoke.unwrap())(&(), &mut chunk, &mut output) {
+        ffi::duckdb_scalar_function_set_error(info, c_string(&e.to_string()).as_ptr());
+    }
+}
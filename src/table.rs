@@ -0,0 +1,284 @@
+// The fakeit table function: generates a whole typed relation in one call.
+//
+//   FROM fakeit(rows := 1000, columns := {'name': 'name_full', 'email': 'contact_email'})
+//
+// Every column maps to a generator by name (see registry.rs), or to a STRUCT
+// such as {'generator': 'contact_email', 'null_rate': 0.3} that also sets the
+// share of NULLs for that column, overriding the `null_rate` parameter. The
+// columns listed in `unique_columns` never repeat a value within one scan (see
+// unique.rs). Output is produced in standard-size chunks, and only the
+// projected columns are generated.
+
+use duckdb::core::{DataChunkHandle, FlatVector, Inserter, LogicalTypeHandle};
+use duckdb::vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab};
+use libduckdb_sys as ffi;
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use super::functions::{self, Parameters, Type};
+use super::registry::{self, Generator};
+use super::rng;
+use super::unique::Issued;
+use super::uuid;
+use super::value::RawValue;
+
+struct Column {
+    generator: Generator,
+    // Probability of a NULL in each row
+    null_rate: f64,
+    // Listed in unique_columns
+    unique: bool,
+}
+
+pub struct FakeitBindData {
+    rows: u64,
+    columns: Vec<Column>,
+    names: Vec<String>,
+}
+
+pub struct FakeitInitData {
+    next_row: AtomicU64,
+    // Bind column index of every output column, in output order
+    projection: Vec<usize>,
+    // Values issued so far, by bind column index
+    issued: Mutex<HashMap<usize, Issued>>,
+}
+
+pub struct FakeitTable;
+
+impl Parameters for FakeitTable {
+    fn named_parameters() -> Vec<(&'static str, Type)> {
+        vec![
+            ("rows", Type::Bigint),
+            ("columns", Type::Any),
+            ("null_rate", Type::Double),
+            ("unique_columns", Type::List(&Type::Varchar)),
+        ]
+    }
+}
+
+impl VTab for FakeitTable {
+    type InitData = FakeitInitData;
+    type BindData = FakeitBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn Error>> {
+        let rows = match bind.get_named_parameter("rows") {
+            Some(rows) => rows.to_int64(),
+            None => return Err("fakeit: the rows parameter is required".into()),
+        };
+        if rows < 0 {
+            return Err(format!("fakeit: rows must not be negative, got {}", rows).into());
+        }
+
+        let spec = match bind.get_named_parameter("columns") {
+            Some(columns) => RawValue::from(columns),
+            None => return Err("fakeit: the columns parameter is required".into()),
+        };
+        let entries = spec
+            .entries()
+            .ok_or("fakeit: columns must be a STRUCT or MAP of column name to generator name")?;
+        if entries.is_empty() {
+            return Err("fakeit: columns must name at least one column".into());
+        }
+
+        let null_rate = match bind.get_named_parameter("null_rate") {
+            Some(rate) => {
+                let rate = RawValue::from(rate).to_f64();
+                check_null_rate(rate).map_err(|rate| format!("fakeit: null_rate must be between 0 and 1, got {}", rate))?
+            }
+            None => 0.0,
+        };
+
+        let mut columns = Vec::with_capacity(entries.len());
+        let mut names = Vec::with_capacity(entries.len());
+        for (name, spec) in entries {
+            let column = parse_column(&name, &spec, null_rate)?;
+            bind.add_result_column(&name, column.generator.logical_type());
+            names.push(name);
+            columns.push(column);
+        }
+
+        // UNIQUE is a reserved word, hence the longer parameter name
+        if let Some(unique) = bind.get_named_parameter("unique_columns") {
+            let unique = RawValue::from(unique);
+            let items = unique.items().ok_or("fakeit: unique_columns must be a list of column names")?;
+            for item in items {
+                let name = item.to_string();
+                let index = names
+                    .iter()
+                    .position(|n| *n == name)
+                    .ok_or_else(|| format!("fakeit: unique_columns names '{}', which is not a column", name))?;
+                columns[index].unique = true;
+            }
+        }
+
+        bind.set_cardinality(rows as u64, true);
+        Ok(FakeitBindData {
+            rows: rows as u64,
+            columns,
+            names,
+        })
+    }
+
+    fn init(init: &InitInfo) -> Result<Self::InitData, Box<dyn Error>> {
+        let projection = init.get_column_indices().into_iter().map(|i| i as usize).collect();
+        Ok(FakeitInitData {
+            next_row: AtomicU64::new(0),
+            projection,
+            issued: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn Error>> {
+        let bind_data = func.get_bind_data();
+        let init_data = func.get_init_data();
+
+        let chunk_size = unsafe { ffi::duckdb_vector_size() };
+        let start = init_data.next_row.fetch_add(chunk_size, Ordering::Relaxed);
+        if start >= bind_data.rows {
+            output.set_len(0);
+            return Ok(());
+        }
+        let len = (bind_data.rows - start).min(chunk_size) as usize;
+
+        let _stream = rng::lock();
+        let mut issued = init_data.issued.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        for (col, &index) in init_data.projection.iter().enumerate() {
+            // Row-id and other virtual columns are never read
+            let Some(column) = bind_data.columns.get(index) else {
+                continue;
+            };
+            if column.unique {
+                write_unique_column(output, col, column, len, issued.entry(index).or_default())
+                    .map_err(|()| format!("fakeit: column '{}' ran out of unique values", bind_data.names[index]))?;
+            } else {
+                write_column(output, col, column, len);
+            }
+        }
+        output.set_len(len);
+        Ok(())
+    }
+
+    fn supports_pushdown() -> bool {
+        true
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        functions::named_parameters::<Self>()
+    }
+}
+
+// A column spec is either a generator name or a STRUCT/MAP with a `generator`
+// entry and an optional `null_rate`
+fn parse_column(name: &str, spec: &RawValue, default_null_rate: f64) -> Result<Column, String> {
+    let mut generator_name = None;
+    let mut null_rate = default_null_rate;
+    match spec.entries() {
+        Some(settings) => {
+            for (key, value) in settings {
+                match key.as_str() {
+                    "generator" => generator_name = Some(value.to_string()),
+                    "null_rate" => {
+                        null_rate = check_null_rate(value.to_f64()).map_err(|rate| {
+                            format!("fakeit: null_rate for column '{}' must be between 0 and 1, got {}", name, rate)
+                        })?
+                    }
+                    other => {
+                        return Err(format!(
+                            "fakeit: unknown setting '{}' for column '{}'; expected generator or null_rate",
+                            other, name
+                        ))
+                    }
+                }
+            }
+        }
+        None => generator_name = Some(spec.to_string()),
+    }
+    let generator_name = generator_name.ok_or_else(|| format!("fakeit: column '{}' names no generator", name))?;
+    let generator = registry::lookup(&generator_name)
+        .ok_or_else(|| format!("fakeit: unknown generator '{}' for column '{}'", generator_name, name))?;
+    Ok(Column {
+        generator,
+        null_rate,
+        unique: false,
+    })
+}
+
+// A NULL rate as a probability; the error carries the offending value as text
+fn check_null_rate(rate: Option<f64>) -> Result<f64, String> {
+    match rate {
+        Some(rate) if (0.0..=1.0).contains(&rate) => Ok(rate),
+        Some(rate) => Err(rate.to_string()),
+        None => Err("a non-numeric value".to_string()),
+    }
+}
+
+// Fill `len` rows of output column `col`; the caller holds the stream lock
+fn write_column(output: &DataChunkHandle, col: usize, column: &Column, len: usize) {
+    let mut vector = output.flat_vector(col);
+    match column.generator {
+        Generator::Varchar(f) => {
+            for i in 0..len {
+                vector.insert(i, f().as_str());
+            }
+        }
+        Generator::Bigint(f) | Generator::Cents(f) => vector.as_mut_slice_with_len::<i64>(len).fill_with(f),
+        Generator::Double(f) => vector.as_mut_slice_with_len::<f64>(len).fill_with(f),
+        Generator::Boolean(f) => vector.as_mut_slice_with_len::<bool>(len).fill_with(f),
+        Generator::Uuid(f) => vector
+            .as_mut_slice_with_len::<ffi::duckdb_hugeint>(len)
+            .fill_with(|| uuid::to_hugeint(f())),
+    }
+    set_nulls(&mut vector, column, len);
+}
+
+// Like write_column, without repeating a value of `issued`; Err when a column
+// that is not text runs out of values
+fn write_unique_column(
+    output: &DataChunkHandle,
+    col: usize,
+    column: &Column,
+    len: usize,
+    issued: &mut Issued,
+) -> Result<(), ()> {
+    let mut vector = output.flat_vector(col);
+    match column.generator {
+        Generator::Varchar(f) => {
+            for i in 0..len {
+                vector.insert(i, issued.take(f).as_str());
+            }
+        }
+        Generator::Bigint(f) | Generator::Cents(f) => fill_unique(&mut vector, len, issued, f, |v| v)?,
+        Generator::Double(f) => fill_unique(&mut vector, len, issued, f, |v| v)?,
+        Generator::Boolean(f) => fill_unique(&mut vector, len, issued, f, |v| v)?,
+        Generator::Uuid(f) => fill_unique(&mut vector, len, issued, f, uuid::to_hugeint)?,
+    }
+    set_nulls(&mut vector, column, len);
+    Ok(())
+}
+
+// Fill the first `len` rows with values of `draw`, stored as `raw` converts them
+fn fill_unique<T: ToString, R>(
+    vector: &mut FlatVector,
+    len: usize,
+    issued: &mut Issued,
+    mut draw: impl FnMut() -> T,
+    raw: impl Fn(T) -> R,
+) -> Result<(), ()> {
+    for slot in vector.as_mut_slice_with_len::<R>(len) {
+        *slot = raw(issued.take_exact(&mut draw).ok_or(())?);
+    }
+    Ok(())
+}
+
+fn set_nulls(vector: &mut FlatVector, column: &Column, len: usize) {
+    if column.null_rate > 0.0 {
+        for i in 0..len {
+            if rng::unit() < column.null_rate {
+                vector.set_null(i);
+            }
+        }
+    }
+}
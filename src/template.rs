@@ -0,0 +1,78 @@
+// Templates for fakeit_generator_generate, e.g. "{name.first} lives in {address.city}".
+//
+// A placeholder names a generator from registry.rs, with a dot or an underscore
+// between category and function ("name.first" or "name_first"). "{{" and "}}"
+// stand for literal braces. Templates are parsed once and rendered per row.
+
+use super::registry::{self, Generator};
+
+enum Segment {
+    Text(String),
+    Value(Generator),
+}
+
+pub struct Template {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Template, String> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut chars = source.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => return Err(format!("unclosed placeholder '{{{}' in template", name)),
+                        }
+                    }
+                    let generator = registry::lookup(&name.trim().replace('.', "_"))
+                        .ok_or_else(|| format!("unknown generator '{}' in template", name))?;
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    segments.push(Segment::Value(generator));
+                }
+                '}' => return Err("unmatched '}' in template; write '}}' for a literal brace".to_string()),
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+        Ok(Template {
+            source: source.to_string(),
+            segments,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    // The caller holds the stream lock
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => out.push_str(text),
+                Segment::Value(generator) => out.push_str(&generator.to_text()),
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,161 @@
+// Values rendered as text the way DuckDB casts them to VARCHAR, for the
+// functions that return values of other types as text: fakeit(generator, ...)
+// and the samples of fakeit_functions().
+//
+// TIMESTAMP WITH TIME ZONE values are rendered in UTC, since scalar functions
+// do not get to see the TimeZone setting.
+
+use duckdb::core::{FlatVector, ListVector};
+use duckdb::types::DuckString;
+use libduckdb_sys as ffi;
+
+use super::functions::Type;
+use super::kind::MICROS_PER_DAY;
+use super::{number, timeseries, uuid};
+
+// Row `row` of `vector`, which holds values of type `t`; None for NULL
+pub unsafe fn render(vector: ffi::duckdb_vector, t: Type, row: usize) -> Option<String> {
+    let flat = FlatVector::from(vector);
+    if flat.row_is_null(row as u64) {
+        return None;
+    }
+    let read = |size| flat.as_mut_ptr::<u8>().add(row * size);
+    Some(match t {
+        Type::Varchar => DuckString::new(&mut *(read(16) as *mut ffi::duckdb_string_t)).as_str().to_string(),
+        Type::Boolean => (*(read(1) as *const bool)).to_string(),
+        Type::UTinyint => (*read(1)).to_string(),
+        Type::USmallint => (*(read(2) as *const u16)).to_string(),
+        Type::Integer => (*(read(4) as *const i32)).to_string(),
+        Type::Bigint => (*(read(8) as *const i64)).to_string(),
+        Type::UBigint => (*(read(8) as *const u64)).to_string(),
+        Type::Hugeint => number::from_hugeint(*(read(16) as *const ffi::duckdb_hugeint)).to_string(),
+        Type::Double => double(*(read(8) as *const f64)),
+        Type::Decimal(width, scale) => {
+            let units = match width {
+                0..=4 => *(read(2) as *const i16) as i128,
+                5..=9 => *(read(4) as *const i32) as i128,
+                10..=18 => *(read(8) as *const i64) as i128,
+                _ => number::from_hugeint(*(read(16) as *const ffi::duckdb_hugeint)),
+            };
+            decimal(units, scale)
+        }
+        Type::Date => date(*(read(4) as *const i32)),
+        Type::Timestamp => timestamp(*(read(8) as *const i64)),
+        Type::TimestampTz => format!("{}+00", timestamp(*(read(8) as *const i64))),
+        Type::Uuid => uuid::to_text(uuid::from_hugeint(*(read(16) as *const ffi::duckdb_hugeint))),
+        Type::List(child) => {
+            let list = ListVector::from(vector);
+            let (offset, length) = list.get_entry(row);
+            let items: Vec<String> = (offset..offset + length)
+                .map(|i| match render(ffi::duckdb_list_vector_get_child(vector), *child, i) {
+                    Some(text) if matches!(child, Type::Varchar) => quote(&text, false),
+                    Some(text) => text,
+                    None => "NULL".to_string(),
+                })
+                .collect();
+            format!("[{}]", items.join(", "))
+        }
+        Type::Struct(fields) => {
+            let items: Vec<String> = fields
+                .iter()
+                .enumerate()
+                .map(|(i, &(name, t))| {
+                    let value = match render(ffi::duckdb_struct_vector_get_child(vector, i as u64), t, row) {
+                        Some(text) if matches!(t, Type::Varchar) => quote(&text, false),
+                        Some(text) => text,
+                        None => "NULL".to_string(),
+                    };
+                    format!("{}: {}", quote(name, true), value)
+                })
+                .collect();
+            format!("{{{}}}", items.join(", "))
+        }
+        Type::Any | Type::Interval | Type::Table => return None,
+    })
+}
+
+// Shortest text that reads back as the same double, in positional notation
+// for exponents from -4 to 15 and in scientific notation otherwise
+pub fn double(value: f64) -> String {
+    if !value.is_finite() {
+        return if value.is_nan() { "nan" } else if value > 0.0 { "inf" } else { "-inf" }.to_string();
+    }
+    let scientific = format!("{:e}", value);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    if (-4..16).contains(&exponent) {
+        let text = value.to_string();
+        if text.contains('.') {
+            text
+        } else {
+            format!("{}.0", text)
+        }
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", mantissa, sign, exponent.abs())
+    }
+}
+
+// A count of 10^-scale as a decimal number
+pub fn decimal(units: i128, scale: u8) -> String {
+    let sign = if units < 0 { "-" } else { "" };
+    let digits = units.unsigned_abs().to_string();
+    let scale = scale as usize;
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, whole, fraction)
+}
+
+// Days since 1970-01-01 as YYYY-MM-DD, with years before 1 marked (BC)
+pub fn date(days: i32) -> String {
+    match days {
+        i32::MAX => "infinity".to_string(),
+        i32::MIN..=-2147483647 => "-infinity".to_string(),
+        _ => {
+            let (year, month, day) = timeseries::civil_from_days(days as i64);
+            match year {
+                ..=0 => format!("{:04}-{:02}-{:02} (BC)", 1 - year, month, day),
+                _ => format!("{:04}-{:02}-{:02}", year, month, day),
+            }
+        }
+    }
+}
+
+// Microseconds since 1970-01-01 as a date and a time of day whose fraction of
+// a second drops trailing zeros
+pub fn timestamp(micros: i64) -> String {
+    match micros {
+        i64::MAX => return "infinity".to_string(),
+        i64::MIN..=-9223372036854775807 => return "-infinity".to_string(),
+        _ => {}
+    }
+    let days = micros.div_euclid(MICROS_PER_DAY as i64);
+    let time = micros.rem_euclid(MICROS_PER_DAY as i64);
+    let seconds = time / 1_000_000;
+    let mut text = format!("{} {:02}:{:02}:{:02}", date(days as i32), seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let fraction = time % 1_000_000;
+    if fraction != 0 {
+        text.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+    }
+    text
+}
+
+// Text inside a list or struct, quoted where it would be ambiguous. Struct
+// field names are always quoted.
+fn quote(text: &str, always: bool) -> String {
+    let special = |c: char| matches!(c, '"' | '\'' | '(' | ')' | ',' | ':' | '=' | '[' | ']' | '{' | '}');
+    let space = |c: Option<char>| c.is_some_and(|c| matches!(c, ' ' | '\t' | '\n' | '\x0b' | '\x0c' | '\r'));
+    let needed = always
+        || text.is_empty()
+        || space(text.chars().next())
+        || (text.len() >= 2 && space(text.chars().last()))
+        || text.eq_ignore_ascii_case("null")
+        || text.chars().any(special);
+    if !needed {
+        return text.to_string();
+    }
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -0,0 +1,335 @@
+// The fakeit_timeseries table function: metric-like series over time.
+//
+//   FROM fakeit_timeseries(TIMESTAMP '2024-01-01', TIMESTAMP '2024-01-31', INTERVAL 1 HOUR, series := 3, daily := 10)
+//
+// Timestamps run from start to end inclusive, like generate_series. Every
+// series keeps a level that starts at `base` and moves at each timestamp by
+// the trend, a random-walk step and, now and then, a step change. The value
+// is that level plus daily and weekly seasonality and Gaussian noise. Rates
+// are per day, so the same settings give the same shape at any interval.
+
+use duckdb::core::{DataChunkHandle, LogicalTypeHandle, LogicalTypeId};
+use duckdb::vtab::{BindInfo, InitInfo, TableFunctionInfo, VTab};
+use libduckdb_sys as ffi;
+use std::error::Error;
+use std::f64::consts::TAU;
+use std::sync::Mutex;
+
+use super::distribution::standard_normal;
+use super::functions::{self, Parameters, Type};
+use super::rng;
+use super::value::RawValue;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+// Every series keeps its level while the scan moves through the timestamps
+const SERIES_MAX: i64 = 100_000;
+
+// Settings that shape the values
+struct Shape {
+    base: f64,
+    // Change of the level per day
+    trend: f64,
+    // Standard deviation of the random walk after one day
+    walk: f64,
+    // Amplitudes of the seasonal waves
+    daily: f64,
+    weekly: f64,
+    // Standard deviation of the noise on each value
+    noise: f64,
+    // Expected step changes per day, and the standard deviation of each
+    step_rate: f64,
+    step_size: f64,
+    min: f64,
+    max: f64,
+}
+
+// Named parameters that are settings of the shape, with their defaults
+const SETTINGS: &[(&str, f64)] = &[
+    ("base", 100.0),
+    ("trend", 0.0),
+    ("walk", 0.0),
+    ("daily", 0.0),
+    ("weekly", 0.0),
+    ("noise", 1.0),
+    ("step_rate", 0.0),
+    ("step_size", 0.0),
+    ("min", f64::NEG_INFINITY),
+    ("max", f64::INFINITY),
+];
+
+pub struct TimeseriesBindData {
+    start: i64,
+    end: i64,
+    interval: ffi::duckdb_interval,
+    series: u64,
+    shape: Shape,
+}
+
+// Position of the scan: the current timestamp with the values of all series
+// at it, and the next series to emit
+struct Cursor {
+    point: i64,
+    timestamp: Option<i64>,
+    levels: Vec<f64>,
+    values: Vec<f64>,
+    next_series: usize,
+}
+
+pub struct TimeseriesInitData {
+    cursor: Mutex<Cursor>,
+}
+
+pub struct TimeseriesTable;
+
+impl Parameters for TimeseriesTable {
+    const PARAMETERS: &'static [Type] = &[Type::Timestamp, Type::Timestamp, Type::Interval];
+
+    fn named_parameters() -> Vec<(&'static str, Type)> {
+        let settings = SETTINGS.iter().map(|&(name, _)| (name, Type::Double));
+        [("series", Type::Bigint)].into_iter().chain(settings).collect()
+    }
+}
+
+impl VTab for TimeseriesTable {
+    type InitData = TimeseriesInitData;
+    type BindData = TimeseriesBindData;
+
+    fn bind(bind: &BindInfo) -> Result<Self::BindData, Box<dyn Error>> {
+        let start = RawValue::from(bind.get_parameter(0)).timestamp_micros();
+        let end = RawValue::from(bind.get_parameter(1)).timestamp_micros();
+        let interval = RawValue::from(bind.get_parameter(2)).interval();
+        // Infinite timestamps are stored as the extremes
+        if [start, end].iter().any(|&t| t == i64::MAX || t == -i64::MAX) {
+            return Err("fakeit_timeseries: start and end must be finite".into());
+        }
+        let parts = [interval.months as i64, interval.days as i64, interval.micros];
+        if parts.iter().any(|&part| part < 0) || parts.iter().all(|&part| part == 0) {
+            return Err("fakeit_timeseries: the interval must be positive".into());
+        }
+
+        let series = match bind.get_named_parameter("series") {
+            Some(series) if !(0..=SERIES_MAX).contains(&series.to_int64()) => {
+                return Err(format!(
+                    "fakeit_timeseries: the number of series must be between 0 and {}, got {}",
+                    SERIES_MAX,
+                    series.to_int64()
+                )
+                .into());
+            }
+            Some(series) => series.to_int64() as u64,
+            None => 1,
+        };
+
+        let mut settings = [0.0; SETTINGS.len()];
+        for (setting, &(name, default)) in settings.iter_mut().zip(SETTINGS) {
+            *setting = match bind.get_named_parameter(name) {
+                Some(value) => {
+                    let value = RawValue::from(value).to_f64().filter(|v| v.is_finite());
+                    value.ok_or_else(|| format!("fakeit_timeseries: {} must be a finite number", name))?
+                }
+                None => default,
+            };
+        }
+        let [base, trend, walk, daily, weekly, noise, step_rate, step_size, min, max] = settings;
+        for (name, value) in [("walk", walk), ("noise", noise), ("step_rate", step_rate), ("step_size", step_size)] {
+            if value < 0.0 {
+                return Err(format!("fakeit_timeseries: {} must not be negative, got {}", name, value).into());
+            }
+        }
+        if min > max {
+            return Err("fakeit_timeseries: min must not be greater than max".into());
+        }
+
+        bind.add_result_column("series_id", LogicalTypeHandle::from(LogicalTypeId::Bigint));
+        bind.add_result_column("ts", LogicalTypeHandle::from(LogicalTypeId::Timestamp));
+        bind.add_result_column("value", LogicalTypeHandle::from(LogicalTypeId::Double));
+        // Exact unless months make the steps uneven; a step too long to count
+        // in microseconds leaves room for little more than the start
+        let step = (interval.days as i64)
+            .checked_mul(MICROS_PER_DAY)
+            .and_then(|step| step.checked_add(interval.micros))
+            .and_then(|step| step.checked_add((interval.months as i64).checked_mul(30 * MICROS_PER_DAY)?));
+        if end < start {
+            bind.set_cardinality(0, true);
+        } else if let Some(step) = step {
+            let points = ((end as i128 - start as i128) / step as i128 + 1) as u64;
+            bind.set_cardinality(points.saturating_mul(series), interval.months == 0);
+        } else {
+            bind.set_cardinality(series, false);
+        }
+
+        Ok(TimeseriesBindData {
+            start,
+            end,
+            interval,
+            series,
+            shape: Shape {
+                base,
+                trend,
+                walk,
+                daily,
+                weekly,
+                noise,
+                step_rate,
+                step_size,
+                min,
+                max,
+            },
+        })
+    }
+
+    fn init(_: &InitInfo) -> Result<Self::InitData, Box<dyn Error>> {
+        Ok(TimeseriesInitData {
+            cursor: Mutex::new(Cursor {
+                point: 0,
+                timestamp: None,
+                levels: Vec::new(),
+                values: Vec::new(),
+                next_series: 0,
+            }),
+        })
+    }
+
+    fn func(func: &TableFunctionInfo<Self>, output: &mut DataChunkHandle) -> Result<(), Box<dyn Error>> {
+        let bind_data = func.get_bind_data();
+        let init_data = func.get_init_data();
+        let chunk_size = unsafe { ffi::duckdb_vector_size() } as usize;
+        let series = bind_data.series as usize;
+
+        let _stream = rng::lock();
+        let mut cursor = init_data.cursor.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut ids = output.flat_vector(0);
+        let mut timestamps = output.flat_vector(1);
+        let mut values = output.flat_vector(2);
+        let mut len = 0;
+        while len < chunk_size && series > 0 {
+            match cursor.timestamp {
+                Some(timestamp) if cursor.next_series < series => {
+                    let i = cursor.next_series;
+                    ids.as_mut_slice::<i64>()[len] = i as i64 + 1;
+                    timestamps.as_mut_slice::<i64>()[len] = timestamp;
+                    values.as_mut_slice::<f64>()[len] = cursor.values[i];
+                    cursor.next_series += 1;
+                    len += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            let Some(timestamp) = timestamp_at(bind_data, cursor.point).filter(|&t| t <= bind_data.end) else {
+                break;
+            };
+            advance(&bind_data.shape, &mut cursor, timestamp, series);
+            cursor.point += 1;
+        }
+        output.set_len(len);
+        Ok(())
+    }
+
+    fn parameters() -> Option<Vec<LogicalTypeHandle>> {
+        functions::parameters::<Self>()
+    }
+
+    fn named_parameters() -> Option<Vec<(String, LogicalTypeHandle)>> {
+        functions::named_parameters::<Self>()
+    }
+}
+
+// Move every series to `timestamp` and compute its values there
+fn advance(shape: &Shape, cursor: &mut Cursor, timestamp: i64, series: usize) {
+    match cursor.timestamp {
+        None => cursor.levels = vec![shape.base; series],
+        Some(previous) => {
+            let days = (timestamp - previous) as f64 / MICROS_PER_DAY as f64;
+            let step_chance = 1.0 - (-shape.step_rate * days).exp();
+            for level in cursor.levels.iter_mut() {
+                *level += shape.trend * days;
+                if shape.walk > 0.0 {
+                    *level += shape.walk * days.sqrt() * standard_normal();
+                }
+                if step_chance > 0.0 && rng::unit() < step_chance {
+                    *level += shape.step_size * standard_normal();
+                }
+            }
+        }
+    }
+
+    let seasonal = shape.daily * daily_wave(timestamp) + shape.weekly * weekly_wave(timestamp);
+    cursor.values = cursor
+        .levels
+        .iter()
+        .map(|level| {
+            let noise = if shape.noise > 0.0 { shape.noise * standard_normal() } else { 0.0 };
+            (level + seasonal + noise).clamp(shape.min, shape.max)
+        })
+        .collect();
+    cursor.timestamp = Some(timestamp);
+    cursor.next_series = 0;
+}
+
+// Between -1 at midnight and 1 at noon
+fn daily_wave(timestamp: i64) -> f64 {
+    let day_fraction = timestamp.rem_euclid(MICROS_PER_DAY) as f64 / MICROS_PER_DAY as f64;
+    (TAU * (day_fraction - 0.5)).cos()
+}
+
+// Between -1 at the start of Sunday and 1 at noon on Wednesday
+fn weekly_wave(timestamp: i64) -> f64 {
+    // 1970-01-01 was a Thursday, day 3 of a week that starts on Monday
+    let week_days = (timestamp as f64 / MICROS_PER_DAY as f64 + 3.0).rem_euclid(7.0);
+    (TAU * (week_days - 2.5) / 7.0).cos()
+}
+
+// The timestamp of point `k`, start + k * interval; None once it overflows
+fn timestamp_at(bind_data: &TimeseriesBindData, k: i64) -> Option<i64> {
+    let interval = &bind_data.interval;
+    let mut timestamp = bind_data.start;
+    if interval.months != 0 {
+        timestamp = add_months(timestamp, k.checked_mul(interval.months as i64)?)?;
+    }
+    let micros = interval.days as i128 * MICROS_PER_DAY as i128 + interval.micros as i128;
+    i64::try_from(timestamp as i128 + k as i128 * micros).ok()
+}
+
+// `months` later, on the same day of the month or the last day of a shorter one
+fn add_months(timestamp: i64, months: i64) -> Option<i64> {
+    let days = timestamp.div_euclid(MICROS_PER_DAY);
+    let time = timestamp.rem_euclid(MICROS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let total = (year * 12 + month - 1).checked_add(months)?;
+    let (year, month) = (total.div_euclid(12), total.rem_euclid(12) + 1);
+    let day = day.min(days_in_month(year, month));
+    days_from_civil(year, month, day).checked_mul(MICROS_PER_DAY)?.checked_add(time)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Year, month and day of a day count since 1970-01-01 (proleptic Gregorian)
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
@@ -0,0 +1,151 @@
+// Unique values, for the `unique` parameter of the fakeit table function and
+// for fakeit_unique.
+//
+// Issued values are remembered as text. A generator is drawn again while it
+// repeats itself; once MAX_DRAWS draws in a row came up taken, the dictionary
+// behind it is assumed to be running out and a counter is appended to the
+// last draw instead: "ana" becomes "ana2", then "ana3", and an email gets the
+// counter before its '@' ("ana2@example.com"). Values that are not text have
+// no such fallback.
+
+use duckdb::core::{FlatVector, Inserter};
+use duckdb::types::DuckString;
+use libduckdb_sys as ffi;
+use std::collections::{HashMap, HashSet};
+use std::ffi::c_void;
+use std::sync::{Arc, Mutex};
+
+use super::functions::{self, c_string, Overload};
+use super::registry::{self, Generator};
+use super::rng;
+
+const MAX_DRAWS: usize = 16;
+
+#[derive(Default)]
+pub struct Issued {
+    values: HashSet<String>,
+    // Next counter to try for each value that has been suffixed
+    suffixes: HashMap<String, u64>,
+}
+
+impl Issued {
+    // Count `value` as issued, such as a value already in a table
+    pub fn insert(&mut self, value: String) {
+        self.values.insert(value);
+    }
+
+    // A text value not issued before
+    pub fn take(&mut self, mut draw: impl FnMut() -> String) -> String {
+        let mut value = draw();
+        for _ in 1..MAX_DRAWS {
+            if !self.values.contains(&value) {
+                break;
+            }
+            value = draw();
+        }
+        if self.values.contains(&value) {
+            let counter = self.suffixes.entry(value.clone()).or_insert(2);
+            loop {
+                let suffixed = with_suffix(&value, *counter);
+                *counter += 1;
+                if !self.values.contains(&suffixed) {
+                    value = suffixed;
+                    break;
+                }
+            }
+        }
+        self.values.insert(value.clone());
+        value
+    }
+
+    // A value whose text was not issued before, or None once MAX_DRAWS draws
+    // in a row were all taken
+    pub fn take_exact<T: ToString>(&mut self, mut draw: impl FnMut() -> T) -> Option<T> {
+        for _ in 0..MAX_DRAWS {
+            let value = draw();
+            if self.values.insert(value.to_string()) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+// `value` with `counter` appended, or inserted before the '@' of an email
+fn with_suffix(value: &str, counter: u64) -> String {
+    match value.rfind('@') {
+        Some(at) if at > 0 => format!("{}{}{}", &value[..at], counter, &value[at..]),
+        _ => format!("{}{}", value, counter),
+    }
+}
+
+// Values issued by one call of fakeit_unique in a statement, per function.
+// DuckDB creates it when it binds the call, hands copies of it to the threads
+// running the statement and drops it with the statement.
+#[derive(Default)]
+struct Scope {
+    issued: Mutex<HashMap<&'static str, Issued>>,
+}
+
+// A value of the generator `name` not issued before in `scope`; generators
+// are told apart by the function they name, so "name_first",
+// "fakeit_name_first" and "name.first" share their values
+fn next(scope: &Scope, name: &str) -> Result<String, String> {
+    let function = registry::function(name)
+        .filter(|function| function.generator.is_some())
+        .ok_or_else(|| format!("unknown generator '{}'", name))?;
+    let generator = function.generator.unwrap();
+    let mut issued = scope.issued.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let issued = issued.entry(function.name).or_default();
+    match generator {
+        Generator::Varchar(f) => Ok(issued.take(f)),
+        _ => issued.take_exact(|| generator.to_text()).ok_or_else(|| {
+            format!("generator '{}' ran out of unique values", function.name.strip_prefix("fakeit_").unwrap_or(function.name))
+        }),
+    }
+}
+
+// Registers fakeit_unique through the C API: duckdb-rs gives scalar functions
+// no bind callback, and so no state that lives as long as a statement
+pub unsafe fn register(con: ffi::duckdb_connection, name: &str, overloads: &[Overload]) -> duckdb::Result<()> {
+    functions::register_with_bind(con, name, overloads, bind, invoke, std::ptr::null())
+}
+
+unsafe extern "C" fn bind(info: ffi::duckdb_bind_info) {
+    let scope = Arc::into_raw(Arc::new(Scope::default()));
+    ffi::duckdb_scalar_function_set_bind_data(info, scope as *mut c_void, Some(release));
+    ffi::duckdb_scalar_function_set_bind_data_copy(info, Some(share));
+}
+
+// Copies of the bind data share the scope
+unsafe extern "C" fn share(scope: *mut c_void) -> *mut c_void {
+    Arc::increment_strong_count(scope as *const Scope);
+    scope
+}
+
+unsafe extern "C" fn release(scope: *mut c_void) {
+    drop(Arc::from_raw(scope as *const Scope));
+}
+
+unsafe extern "C" fn invoke(info: ffi::duckdb_function_info, input: ffi::duckdb_data_chunk, output: ffi::duckdb_vector) {
+    let scope = &*(ffi::duckdb_scalar_function_get_bind_data(info) as *const Scope);
+    let len = ffi::duckdb_data_chunk_get_size(input) as usize;
+    let names = FlatVector::from(ffi::duckdb_data_chunk_get_vector(input, 0));
+    let mut output_vec = FlatVector::from(output);
+    let _stream = rng::lock();
+    for (i, &name) in names.as_slice_with_len::<ffi::duckdb_string_t>(len).iter().enumerate() {
+        if names.row_is_null(i as u64) {
+            output_vec.set_null(i);
+            continue;
+        }
+        let mut name = name;
+        match next(scope, DuckString::new(&mut name).as_str().as_ref()) {
+            Ok(value) => output_vec.insert(i, value.as_str()),
+            Err(e) => {
+                let message = c_string(&format!("fakeit_unique: {}", e));
+                ffi::duckdb_scalar_function_set_error(info, message.as_ptr());
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// UUID generation in DuckDB's native representation.
+//
+// A UUID is built as a plain 128-bit big-endian value and converted with
+// `to_hugeint`: DuckDB stores UUIDs as a HUGEINT with the top bit flipped so
+// that signed comparison sorts them like their text form.
+
+use libduckdb_sys as ffi;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::rng;
+
+// 100-nanosecond intervals between 1582-10-15 (the Gregorian reform, the v1
+// epoch) and 1970-01-01
+const GREGORIAN_OFFSET: u128 = 0x01b2_1dd2_1381_4000;
+
+pub fn to_hugeint(uuid: u128) -> ffi::duckdb_hugeint {
+    let flipped = uuid ^ (1 << 127);
+    ffi::duckdb_hugeint {
+        lower: flipped as u64,
+        upper: (flipped >> 64) as i64,
+    }
+}
+
+pub fn from_hugeint(value: ffi::duckdb_hugeint) -> u128 {
+    (((value.upper as u64 as u128) << 64) | value.lower as u128) ^ (1 << 127)
+}
+
+// Canonical 8-4-4-4-12 hex form
+pub fn to_text(uuid: u128) -> String {
+    let hex = format!("{:032x}", uuid);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+fn with_version(bits: u128, version: u128) -> u128 {
+    let bits = (bits & !(0xf << 76)) | (version << 76);
+    // RFC 4122 variant: the top two bits of octet 8 are 10
+    (bits & !(0b11 << 62)) | (0b10 << 62)
+}
+
+fn now() -> std::time::Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+pub fn v4() -> u128 {
+    with_version(rng::bits128(), 4)
+}
+
+// Time-based UUID for the current time. The clock sequence and node are random,
+// with the multicast bit set on the node as RFC 4122 asks for when no MAC
+// address is used.
+pub fn v1() -> u128 {
+    let ticks = now().as_nanos() / 100 + GREGORIAN_OFFSET;
+    let time_low = ticks & 0xffff_ffff;
+    let time_mid = (ticks >> 32) & 0xffff;
+    let time_high = (ticks >> 48) & 0x0fff;
+    let random = rng::bits128();
+    let node = (random & 0xffff_ffff_ffff) | (1 << 40);
+    let clock_seq = (random >> 48) & 0x3fff;
+    let bits = (time_low << 96) | (time_mid << 80) | (time_high << 64) | (clock_seq << 48) | node;
+    with_version(bits, 1)
+}
+
+// Time-ordered UUID: 48 bits of Unix milliseconds followed by random bits
+pub fn v7(unix_millis: u64) -> u128 {
+    let random = rng::bits128() & ((1 << 80) - 1);
+    with_version(((unix_millis as u128 & 0xffff_ffff_ffff) << 80) | random, 7)
+}
+
+pub fn v7_now() -> u128 {
+    v7(now().as_millis() as u64)
+}
+
+// Name-based UUID: SHA-1 of the namespace followed by the name
+pub fn v5(namespace: u128, name: &[u8]) -> u128 {
+    let mut hasher = sha1_smol::Sha1::new();
+    hasher.update(&namespace.to_be_bytes());
+    hasher.update(name);
+    let digest = hasher.digest().bytes();
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    with_version(u128::from_be_bytes(bytes), 5)
+}
@@ -0,0 +1,102 @@
+// Access to nested table function parameters (STRUCT, MAP and LIST values).
+//
+// `duckdb::vtab::Value` only exposes scalar conversions, so bound parameters
+// are unwrapped into their raw handle and walked through the C API.
+
+use duckdb::core::LogicalTypeId;
+use duckdb::vtab::Value;
+use libduckdb_sys as ffi;
+use std::ffi::{c_void, CStr};
+use std::fmt;
+
+pub struct RawValue(ffi::duckdb_value);
+
+impl From<Value> for RawValue {
+    fn from(value: Value) -> Self {
+        // `Value` is a thin owner of the handle; take over its ownership
+        RawValue(unsafe { std::mem::transmute::<Value, ffi::duckdb_value>(value) })
+    }
+}
+
+impl Drop for RawValue {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { ffi::duckdb_destroy_value(&mut self.0) };
+        }
+    }
+}
+
+impl fmt::Display for RawValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        unsafe {
+            let varchar = ffi::duckdb_get_varchar(self.0);
+            let res = write!(f, "{}", CStr::from_ptr(varchar).to_string_lossy());
+            ffi::duckdb_free(varchar as *mut c_void);
+            res
+        }
+    }
+}
+
+impl RawValue {
+    pub fn type_id(&self) -> LogicalTypeId {
+        unsafe { LogicalTypeId::from(ffi::duckdb_get_type_id(ffi::duckdb_get_value_type(self.0))) }
+    }
+
+    // Numeric value, read through its text form so that DECIMAL literals such
+    // as 0.25 work as well as DOUBLE; None for anything that is not a number
+    pub fn to_f64(&self) -> Option<f64> {
+        self.to_string().trim().parse().ok()
+    }
+
+    // Microseconds since 1970-01-01 of a TIMESTAMP value
+    pub fn timestamp_micros(&self) -> i64 {
+        unsafe { ffi::duckdb_get_timestamp(self.0).micros }
+    }
+
+    pub fn interval(&self) -> ffi::duckdb_interval {
+        unsafe { ffi::duckdb_get_interval(self.0) }
+    }
+
+    // Entries of a STRUCT or MAP value as (name, value) pairs, in order
+    pub fn entries(&self) -> Option<Vec<(String, RawValue)>> {
+        unsafe {
+            match self.type_id() {
+                LogicalTypeId::Struct => {
+                    let logical_type = ffi::duckdb_get_value_type(self.0);
+                    let count = ffi::duckdb_struct_type_child_count(logical_type);
+                    let mut entries = Vec::with_capacity(count as usize);
+                    for i in 0..count {
+                        let name = ffi::duckdb_struct_type_child_name(logical_type, i);
+                        let key = CStr::from_ptr(name).to_string_lossy().into_owned();
+                        ffi::duckdb_free(name as *mut c_void);
+                        entries.push((key, RawValue(ffi::duckdb_get_struct_child(self.0, i))));
+                    }
+                    Some(entries)
+                }
+                LogicalTypeId::Map => {
+                    let count = ffi::duckdb_get_map_size(self.0);
+                    let mut entries = Vec::with_capacity(count as usize);
+                    for i in 0..count {
+                        let key = RawValue(ffi::duckdb_get_map_key(self.0, i)).to_string();
+                        entries.push((key, RawValue(ffi::duckdb_get_map_value(self.0, i))));
+                    }
+                    Some(entries)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    // Elements of a LIST value, in order
+    pub fn items(&self) -> Option<Vec<RawValue>> {
+        unsafe {
+            match self.type_id() {
+                LogicalTypeId::List => {
+                    let count = ffi::duckdb_get_list_size(self.0);
+                    Some((0..count).map(|i| RawValue(ffi::duckdb_get_list_child(self.0, i))).collect())
+                }
+                _ => None,
+            }
+        }
+    }
+}